@@ -0,0 +1,82 @@
+use crate::types::{
+    node::Node,
+    object::{Object, ObjectValue},
+};
+
+/// Renders the #️⃣📖 doc comments of the top-level functions of a program as markdown.
+pub fn generate(program: &Node) -> String {
+    let Node::Program { statements } = program else {
+        return String::new();
+    };
+
+    statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Node::ExpressionStatement { expression } => documented_function(expression, None),
+            _ => None,
+        })
+        .map(|(signature, doc)| format!("## {signature}\n\n{doc}\n"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+/// Returns the documentation shown by the REPL help for a value.
+pub fn describe(object: &Object) -> Option<String> {
+    match object.value() {
+        ObjectValue::Function { doc, .. } => doc.clone(),
+        ObjectValue::BuiltinFunction(function) => Some(String::from(function.doc())),
+        _ => None,
+    }
+}
+
+// a named function, or an anonymous one assigned to an identifier
+fn documented_function(node: &Node, assigned_name: Option<String>) -> Option<(String, String)> {
+    match node {
+        Node::FunctionLiteral {
+            name,
+            doc: Some(doc),
+            parameters,
+            ..
+        } => {
+            let name = name.as_ref().map(|name| name.string()).or(assigned_name)?;
+            let parameters = parameters
+                .iter()
+                .map(|parameter| parameter.string())
+                .collect::<Vec<String>>()
+                .join("🦶 ");
+            Some((format!("📛 {name}🌜{parameters}🌛"), doc.clone()))
+        }
+        Node::AssignExpression {
+            identifier, value, ..
+        } => documented_function(value, Some(identifier.string())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod doc_test {
+    use super::*;
+    use crate::{lexer::Lexer, parser::Parser};
+
+    #[test]
+    fn test_generate() {
+        let source = "
+        #️⃣🫸 not a doc #️⃣🫸 nested 🫷#️⃣ still a comment 🫷#️⃣
+        #️⃣📖 Adds two numbers.
+        #️⃣📖 The second one is optional.
+        📛 🧮🌜🅰️🦶 🅱️ ⬅️ 1️⃣🌛 🫸 🅰️ ➕ 🅱️ 🫷
+        📛 🙈🌜🌛 🫸 🈳 🫷
+        🔢 ⬅️ #️⃣📖 Doubles a number.
+        📛 🌜🅰️🌛 🫸 🅰️ ✖️ 2️⃣ 🫷
+        ";
+        let target = "## 📛 🧮🌜🅰️🦶 🅱️ ⬅️ 1️⃣🌛\n\nAdds two numbers.\nThe second one is optional.\n\n\
+                      ## 📛 🔢🌜🅰️🌛\n\nDoubles a number.\n";
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+        assert_eq!(generate(&program), target);
+    }
+}
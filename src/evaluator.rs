@@ -1,8 +1,51 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt, rc::Rc};
 
-use crate::types::{Node, Token, object::*};
+use indexmap::{IndexMap, IndexSet};
 
-pub fn eval(node: Node, env: &mut Environment) -> Result<Object, String> {
+use crate::{
+    types::{Node, Token, object::*},
+    util::emoji_convert::object_to_emoji,
+};
+
+type NamedArguments = Vec<(String, Object)>;
+
+/// Aborts the evaluation until a 🤞 catches it or it reaches the caller of `eval`.
+#[derive(Debug, PartialEq, Clone)]
+pub enum EvalError {
+    Exception(Box<Object>),
+    // a 🆖 returned early by ⁉️, caught by the enclosing function call
+    Propagate(Box<Object>),
+}
+
+impl EvalError {
+    fn new(kind: &str, message: String) -> Self {
+        EvalError::Exception(Box::new(Object::new_error(kind.to_string(), message)))
+    }
+}
+
+// plain runtime errors are generic exceptions
+impl From<String> for EvalError {
+    fn from(message: String) -> Self {
+        EvalError::new(GENERIC_ERROR, message)
+    }
+}
+
+impl fmt::Display for EvalError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvalError::Exception(exception) => match exception.value() {
+                ObjectValue::Error { kind, message } if kind == GENERIC_ERROR => {
+                    write!(f, "{message}")
+                }
+                ObjectValue::Error { kind, message } => write!(f, "{kind}: {message}"),
+                _ => write!(f, "Uncaught {}", exception.inspect()),
+            },
+            EvalError::Propagate(result) => write!(f, "Propagated {}", result.inspect()),
+        }
+    }
+}
+
+pub fn eval(node: Node, env: &mut Environment) -> Result<Object, EvalError> {
     match node {
         Node::Program { statements } => eval_program(statements, env),
         Node::ExpressionStatement { expression } => eval(*expression, env),
@@ -10,8 +53,12 @@ pub fn eval(node: Node, env: &mut Environment) -> Result<Object, String> {
         Node::FloatLiteral { value } => Ok(Object::new_float(value)),
         Node::BooleanLiteral { value } => Ok(Object::new_boolean(value)),
         Node::StringLiteral { value } => Ok(Object::new_string(value)),
+        Node::NullLiteral => Ok(Object::new_null()),
+        Node::InterpolatedString { segments } => eval_interpolated_string(segments, env),
         Node::ListLiteral { elements } => eval_list_literal(elements, env),
         Node::MapLiteral { entries } => eval_map_literal(entries, env),
+        Node::SetLiteral { elements } => eval_set_literal(elements, env),
+        Node::TupleLiteral { elements } => eval_tuple_literal(elements, env),
         Node::PrefixExpression { operator, right } => {
             eval_prefix_expression(operator, eval(*right, env)?)
         }
@@ -19,11 +66,22 @@ pub fn eval(node: Node, env: &mut Environment) -> Result<Object, String> {
             left,
             operator,
             right,
-        } => eval_infix_expression(operator, eval(*left, env)?, eval(*right, env)?),
+        } => match operator.as_str() {
+            "🔁" | "🔀" | "❔❔" => eval_logical_expression(operator, *left, *right, env),
+            _ => eval_infix_expression(operator, eval(*left, env)?, eval(*right, env)?),
+        },
         Node::IndexExpression {
             collection: left,
             index,
-        } => eval_index_expression(eval(*left, env)?, eval(*index, env)?),
+            optional,
+        } => {
+            let left = eval(*left, env)?;
+            if optional {
+                eval_optional_index_expression(left, *index, env)
+            } else {
+                eval_index_expression(left, eval(*index, env)?)
+            }
+        }
         Node::BlockStatement { statements } => eval_block_statements(statements, env),
         Node::IfExpression {
             condition,
@@ -33,131 +91,399 @@ pub fn eval(node: Node, env: &mut Environment) -> Result<Object, String> {
         Node::WhileExpression { condition, body } => eval_while_expression(*condition, *body, env),
         Node::BreakExpression { value } => eval_break_expression(value, env),
         Node::ReturnStatement { value } => Ok(Object::new_return_value(eval(*value, env)?)),
-        Node::AssignExpression { identifier, value } => {
-            eval_assign_expression(*identifier, *value, env)
-        }
+        Node::AssignExpression {
+            identifier,
+            operator,
+            value,
+        } => eval_assign_expression(*identifier, operator, *value, env),
+        Node::DeclareExpression {
+            mutable,
+            identifier,
+            value,
+        } => eval_declare_expression(mutable, *identifier, *value, env),
         Node::Identifier { value } => eval_identifier(&value, env),
         Node::FunctionLiteral {
             name,
+            doc,
             parameters,
             body,
         } => {
-            let function = Object::new_function(parameters, body, env.clone());
+            let name = name.map(|name| name.string());
+            let function = Object::new_function(name.clone(), doc, parameters, body, env.clone());
             if let Some(name) = name {
-                env.set(name.string(), function.clone());
+                env.assign(name, function.clone())?;
             }
             Ok(function)
         }
+        Node::MatchExpression { subject, arms } => eval_match_expression(*subject, arms, env),
+        Node::TryExpression {
+            body,
+            error,
+            handler,
+            finalizer,
+        } => eval_try_expression(*body, error, handler, finalizer, env),
+        Node::ThrowExpression { value } => Err(eval_throw_expression(eval(*value, env)?)),
+        Node::DeleteExpression { target } => eval_delete_expression(*target, env),
+        Node::PropagateExpression { value } => eval_propagate_expression(eval(*value, env)?),
+        Node::ClassDeclaration {
+            name,
+            parent,
+            methods,
+        } => eval_class_declaration(*name, parent, methods, env),
+        Node::Super => Err(String::from("Unexpected 🦸 outside of a member expression").into()),
+        Node::EnumDeclaration { name, variants } => {
+            let enum_type = Object::new_enum_type(Rc::new(Enum {
+                name: name.string(),
+                variants: variants
+                    .into_iter()
+                    .map(|(variant, fields)| (variant.string(), fields))
+                    .collect(),
+                env: env.clone(),
+            }));
+            env.assign(name.string(), enum_type.clone())?;
+            Ok(enum_type)
+        }
+        Node::RecordDeclaration { name, fields } => {
+            let record_type = Object::new_record_type(name.string(), fields, env.clone());
+            env.assign(name.string(), record_type.clone())?;
+            Ok(record_type)
+        }
+        Node::Wildcard => Err(String::from("Unexpected *️⃣ outside of a pattern").into()),
+        Node::SpreadElement { value } => Err(format!(
+            "Unexpected 🧺{} outside of a list/map pattern",
+            value.string()
+        ).into()),
         Node::CallExpression {
             function,
-            arguments,
+            mut arguments,
         } => {
+            // an undefined function like 📏 in 🅻 ⏩ 📏 is a method of the first argument
+            if let Node::Identifier { value } = function.as_ref()
+                && env.get(value).is_none()
+                && arguments.first().is_some_and(|argument| !matches!(argument, Node::NamedArgument { .. }))
+            {
+                let instance = eval(arguments.remove(0), env)?;
+                return eval_member_expression(instance, Node::CallExpression { function, arguments }, false, env);
+            }
             let function = eval(*function, env)?;
-            let args = eval_expressions(arguments, env)?;
-            apply_function(function, args)
-        }
-        Node::MemberExpression { instance, member } => {
-            eval_member_expression(eval(*instance, env)?, *member)
+            let (args, named_args) = eval_arguments(arguments, env)?;
+            apply_function(function, args, named_args)
         }
+        Node::NamedArgument { name, .. } => Err(format!(
+            "Unexpected named argument {} outside of a call",
+            name.string()
+        ).into()),
+        Node::MemberExpression {
+            instance,
+            member,
+            optional,
+        } => match *instance {
+            Node::Super => eval_super_member_expression(*member, env),
+            instance => eval_member_expression(eval(instance, env)?, *member, optional, env),
+        },
     }
 }
 
-fn eval_program(statements: Vec<Node>, env: &mut Environment) -> Result<Object, String> {
-    let mut result = Err(String::from("Empty statements to evaluate values"));
+fn eval_program(statements: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut result = Err(String::from("Empty statements to evaluate values").into());
     for statement in statements {
         result = eval(statement, env);
-        if let Ok(ref obj) = result
-            && let ObjectValue::ReturnValue(value) = obj.value()
-        {
-            return Ok(*value.clone());
+        match result {
+            Ok(ref obj) => {
+                if let ObjectValue::ReturnValue(value) = obj.value() {
+                    return Ok(*value.clone());
+                }
+            }
+            // a ⁉️ outside of functions returns the 🆖 like 🔙 does
+            Err(EvalError::Propagate(result)) => return Ok(*result),
+            Err(_) => return result,
         }
     }
     result
 }
 
-fn eval_block_statements(statements: Vec<Node>, env: &mut Environment) -> Result<Object, String> {
-    let mut result = Err(String::from("Empty statements to evaluate values"));
+fn eval_block_statements(statements: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut result = Err(String::from("Empty statements to evaluate values").into());
     for statement in statements {
         result = eval(statement, env);
-        if let Ok(ref obj) = result
-            && let ObjectValue::ReturnValue(_) = obj.value()
+        if result
+            .as_ref()
+            .is_ok_and(|obj| !matches!(obj.value(), ObjectValue::ReturnValue(_)))
         {
-            return result;
+            continue;
         }
+        return result;
     }
     result
 }
 
 fn eval_assign_expression(
     identifier: Node,
+    operator: String,
     value: Node,
     env: &mut Environment,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     let value_object = eval(value, env)?;
+    let infix_operator = operator
+        .strip_suffix("⬅️")
+        .filter(|infix_operator| !infix_operator.is_empty());
+    if infix_operator.is_some()
+        && let Node::ListLiteral { .. } | Node::TupleLiteral { .. } | Node::MapLiteral { .. } = identifier
+    {
+        return Err(format!(
+            "Expected a single target for {}, but got {}",
+            operator,
+            identifier.string()
+        ).into());
+    }
+    // compound assignments combine the current value of the target, read once the
+    // receiver and index of the target are evaluated, with the assigned value
+    let combine = |current: &mut dyn FnMut() -> Result<Object, EvalError>| match infix_operator {
+        Some(infix_operator) => eval_infix_expression(infix_operator.to_string(), current()?, value_object.clone()),
+        None => Ok(value_object.clone()),
+    };
     match identifier {
         Node::Identifier { value } => {
-            env.set(value, value_object.clone());
+            let value_object = combine(&mut || eval_identifier(&value, env))?;
+            env.assign(value, value_object.clone())?;
             Ok(value_object)
         }
         Node::IndexExpression {
             collection,
             index,
+            ..
         } => {
-            let mut collection_object = eval(*collection.clone(), env)?; //eval_identifier return cloned object
             let index_object = eval(*index, env)?;
-            match collection_object.value_mut() {
-                ObjectValue::List(elements) => {
-                    if let ObjectValue::Integer(index) = index_object.value()
-                        && *index >= 0
-                    {
-                        if let Some(element) = elements.get_mut(*index as usize) {
-                            *element = value_object.clone();
-                            if let Node::Identifier { value } = *collection {
-                                env.set(value, Object::new_list(elements.to_owned()));
-                            }
-                            Ok(value_object)
-                        } else {
-                            Err(format!("Invalid index: {index}"))
-                        }
-                    } else {
-                        Err(String::from(
-                            "Index must be an integer greater than or equal to 0",
-                        ))
-                    }
-                }
-                ObjectValue::Map(entries) => {
-                    if let Some(element) = entries.get_mut(&index_object) {
-                        *element = value_object.clone();
-                        if let Node::Identifier { value } = *collection {
-                            env.set(value, Object::new_map(entries.to_owned()));
-                        }
-                        Ok(value_object)
-                    } else {
-                        Err(format!("Invalid index: {index_object:?}"))
-                    }
-                }
-                _ => Err(String::from("Invalid collection type in index expression")),
-            }
+            update_place(*collection, env, &mut |collection_object| {
+                let value_object = combine(&mut || {
+                    element_mut(collection_object, &index_object).map(|element| element.clone())
+                })?;
+                assign_index(collection_object, index_object.clone(), value_object)
+            })
         }
-        Node::MemberExpression { instance, member } => {
-            let mut instance_object = eval(*instance.clone(), env)?;
-            if let Node::Identifier { value } = *member {
-                let env = instance_object.associated_env_mut();
-                env.set(value, value_object.clone());
-            }
-            if let Node::Identifier { value } = *instance {
-                env.set(value, instance_object);
+        Node::MemberExpression {
+            instance, member, ..
+        } => match *member {
+            Node::Identifier { value } => update_place(*instance, env, &mut |instance_object| {
+                let value_object = combine(&mut || member_value(instance_object, &value))?;
+                assign_member(instance_object, value.clone(), value_object)
+            }),
+            _ => Ok(value_object),
+        },
+        Node::ListLiteral { .. } | Node::TupleLiteral { .. } | Node::MapLiteral { .. } => {
+            let mut bindings = vec![];
+            match_pattern(&identifier, &value_object, env, &mut bindings, true)?;
+            for (identifier, value) in bindings {
+                env.assign(identifier, value)?;
             }
             Ok(value_object)
         }
         _ => Err(format!(
-            "Expected identifier / index expression / member expression, but got {}",
+            "Expected identifier / index expression / member expression / list, tuple or map pattern, but got {}",
             identifier.string()
+        ).into()),
+    }
+}
+
+// the current value of a field, or of a member of the associated environment
+fn member_value(instance: &Object, name: &String) -> Result<Object, EvalError> {
+    match lookup_field(instance, name) {
+        Some(value) => Ok(value),
+        None => eval_identifier(name, instance.associated_env()),
+    }
+}
+
+// applies `update` to the object stored at a variable or, recursively, at an element or field of an
+// object stored there, writing the updated objects back so nested targets like 🅼👉k1👈❇️🅺 are updated
+fn update_place(
+    place: Node,
+    env: &mut Environment,
+    update: &mut dyn FnMut(&mut Object) -> Result<Object, EvalError>,
+) -> Result<Object, EvalError> {
+    match place {
+        // updated in place, so captures of the variable see the update
+        Node::Identifier { value } => {
+            if env.is_constant(&value) {
+                return Err(format!("Cannot assign to constant {value}").into());
+            }
+            let storage = env
+                .get(&value)
+                .cloned()
+                .ok_or_else(|| format!("identifier not found: {value}"))?;
+            // a parameter updates the variable passed by the caller until it is reassigned
+            let referenced = match storage.borrow().value() {
+                ObjectValue::Reference(referenced) => Some(referenced.clone()),
+                _ => None,
+            };
+            let storage = referenced.unwrap_or(storage);
+            let mut object = storage.borrow().clone();
+            let result = update(&mut object)?;
+            *storage.borrow_mut() = object;
+            Ok(result)
+        }
+        Node::IndexExpression {
+            collection, index, ..
+        } => {
+            let index_object = eval(*index, env)?;
+            update_place(*collection, env, &mut |collection_object| {
+                update(element_mut(collection_object, &index_object)?)
+            })
+        }
+        Node::MemberExpression {
+            instance, ref member, ..
+        } if let Node::Identifier { value: name } = member.as_ref() => {
+            update_place(*instance, env, &mut |instance_object| {
+                update_field(instance_object, name, update)
+            })
+        }
+        // other expressions evaluate to temporary values, which are updated but not stored
+        place => update(&mut eval(place, env)?),
+    }
+}
+
+fn element_mut<'a>(collection: &'a mut Object, index: &Object) -> Result<&'a mut Object, EvalError> {
+    match collection.value_mut() {
+        ObjectValue::List(elements) => {
+            let position = list_position(index)?;
+            elements
+                .get_mut(position)
+                .ok_or_else(|| EvalError::new(INDEX_ERROR, format!("Invalid index: {position}")))
+        }
+        ObjectValue::Map(entries) => entries
+            .get_mut(index)
+            .ok_or_else(|| EvalError::new(INDEX_ERROR, format!("Invalid index: {}", index.inspect()))),
+        ObjectValue::Tuple(_) => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Cannot assign to an element of a tuple"),
+        )),
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Invalid collection type in index expression"),
+        )),
+    }
+}
+
+fn update_field(
+    instance: &mut Object,
+    name: &String,
+    update: &mut dyn FnMut(&mut Object) -> Result<Object, EvalError>,
+) -> Result<Object, EvalError> {
+    let missing_field = |type_name: &String| {
+        EvalError::new(TYPE_ERROR, format!("{type_name} has no field {name}"))
+    };
+    match instance.value_mut() {
+        ObjectValue::Record { type_name, fields } => match fields.iter_mut().find(|(field, _)| field == name) {
+            Some((_, value)) => update(value),
+            None => Err(missing_field(&format!("Record {type_name}"))),
+        },
+        ObjectValue::Instance { class, fields } => {
+            match fields.borrow_mut().iter_mut().find(|(field, _)| field == name) {
+                Some((_, value)) => update(value),
+                None => Err(missing_field(&format!("Instance of {}", class.name))),
+            }
+        }
+        ObjectValue::Variant { enum_name, name: variant, .. } => Err(EvalError::new(
+            TYPE_ERROR,
+            format!("Cannot assign to field {name} of variant {enum_name}❇️{variant}"),
+        )),
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            format!("Cannot assign to field {name} of {}", instance.inspect()),
         )),
     }
 }
 
-fn eval_list_literal(elements: Vec<Node>, env: &mut Environment) -> Result<Object, String> {
+// records only have their declared fields, while instances can get new ones
+fn assign_member(instance: &mut Object, name: String, value: Object) -> Result<Object, EvalError> {
+    match instance.value_mut() {
+        ObjectValue::Record { .. } | ObjectValue::Variant { .. } => {
+            update_field(instance, &name, &mut |field| {
+                *field = value.clone();
+                Ok(value.clone())
+            })
+        }
+        ObjectValue::Instance { fields, .. } => {
+            let mut fields = fields.borrow_mut();
+            match fields.iter_mut().find(|(field, _)| *field == name) {
+                Some((_, field)) => *field = value.clone(),
+                None => fields.push((name, value.clone())),
+            }
+            Ok(value)
+        }
+        _ => {
+            instance.associated_env_mut().assign(name, value.clone())?;
+            Ok(value)
+        }
+    }
+}
+
+// replaces an element of a list, or inserts or replaces the entry of a map
+fn assign_index(collection: &mut Object, index: Object, value: Object) -> Result<Object, EvalError> {
+    if let ObjectValue::Map(entries) = collection.value_mut() {
+        entries.insert(index, value.clone());
+    } else {
+        *element_mut(collection, &index)? = value.clone();
+    }
+    Ok(value)
+}
+
+// removes an element of a list or the entry of a map, returning the removed value
+fn remove_index(collection: &mut Object, index: &Object) -> Result<Object, EvalError> {
+    match collection.value_mut() {
+        ObjectValue::List(elements) => {
+            let position = list_position(index)?;
+            if position < elements.len() {
+                Ok(elements.remove(position))
+            } else {
+                Err(EvalError::new(INDEX_ERROR, format!("Invalid index: {position}")))
+            }
+        }
+        ObjectValue::Map(entries) => entries
+            .shift_remove(index)
+            .ok_or_else(|| EvalError::new(INDEX_ERROR, format!("Invalid index: {}", index.inspect()))),
+        ObjectValue::Tuple(_) => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Cannot delete an element of a tuple"),
+        )),
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Invalid collection type in index expression"),
+        )),
+    }
+}
+
+fn list_position(index: &Object) -> Result<usize, EvalError> {
+    match index.value() {
+        ObjectValue::Integer(index) if *index >= 0 => Ok(*index as usize),
+        _ => Err(EvalError::new(
+            INDEX_ERROR,
+            String::from("Index must be an integer greater than or equal to 0"),
+        )),
+    }
+}
+
+fn eval_delete_expression(target: Node, env: &mut Environment) -> Result<Object, EvalError> {
+    let Node::IndexExpression {
+        collection, index, ..
+    } = target
+    else {
+        return Err(format!("Expected an index expression to 🗑️, but got {}", target.string()).into());
+    };
+    let index_object = eval(*index, env)?;
+    update_place(*collection, env, &mut |collection_object| {
+        remove_index(collection_object, &index_object)
+    })
+}
+
+fn eval_interpolated_string(segments: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut value = String::new();
+    for segment in segments {
+        value.push_str(&object_to_emoji(&eval(segment, env)?)?);
+    }
+    Ok(Object::new_string(value))
+}
+
+fn eval_list_literal(elements: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
     let mut value = vec![];
     for node in elements {
         value.push(eval(node, env)?);
@@ -165,55 +491,81 @@ fn eval_list_literal(elements: Vec<Node>, env: &mut Environment) -> Result<Objec
     Ok(Object::new_list(value))
 }
 
-fn eval_map_literal(entries: Vec<(Node, Node)>, env: &mut Environment) -> Result<Object, String> {
-    let mut value = HashMap::new();
+fn eval_map_literal(entries: Vec<(Node, Node)>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut value = IndexMap::new();
     for (key, val) in entries {
         value.insert(eval(key, env)?, eval(val, env)?);
     }
     Ok(Object::new_map(value))
 }
 
-fn eval_prefix_expression(operator: String, right: Object) -> Result<Object, String> {
+fn eval_tuple_literal(elements: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut value = vec![];
+    for node in elements {
+        value.push(eval(node, env)?);
+    }
+    Ok(Object::new_tuple(value))
+}
+
+fn eval_set_literal(elements: Vec<Node>, env: &mut Environment) -> Result<Object, EvalError> {
+    let mut value = IndexSet::new();
+    for node in elements {
+        value.insert(eval(node, env)?);
+    }
+    Ok(Object::new_set(value))
+}
+
+fn eval_prefix_expression(operator: String, right: Object) -> Result<Object, EvalError> {
     match operator.as_str() {
         "⏸️" => eval_prefix_not_expression(&right),
         "➖" => eval_prefix_minus_expression(&right),
         _ => Err(String::from(
             "Invalid prefix expressions to evaluate values",
-        )),
+        ).into()),
     }
 }
 
-fn eval_prefix_not_expression(obj: &Object) -> Result<Object, String> {
+fn eval_prefix_not_expression(obj: &Object) -> Result<Object, EvalError> {
     if let ObjectValue::ReturnValue(_) = &obj.value() {
         return Err(String::from(
             "Invalid prefix not expression to evaluate return expression",
-        ));
+        ).into());
     }
-    let value = match obj.value() {
-        ObjectValue::Integer(value) => *value > 0,
-        ObjectValue::Float(value) => *value > 0.0,
-        ObjectValue::Boolean(value) => *value,
-        ObjectValue::String(value) => !value.is_empty(),
-        ObjectValue::Null => false,
-        ObjectValue::List(value) => !value.is_empty(),
-        ObjectValue::Map(value) => !value.is_empty(),
-        _ => false,
-    };
-    Ok(Object::new_boolean(!value))
+    Ok(Object::new_boolean(!is_truthy(obj)))
 }
 
-fn eval_prefix_minus_expression(obj: &Object) -> Result<Object, String> {
+fn eval_prefix_minus_expression(obj: &Object) -> Result<Object, EvalError> {
     match obj.value() {
-        ObjectValue::Integer(value) => Ok(Object::new_integer(-value)),
+        ObjectValue::Integer(value) => value.checked_neg().map(Object::new_integer).ok_or_else(|| {
+            EvalError::new(OVERFLOW_ERROR, format!("Integer overflow evaluating ➖{value}"))
+        }),
         ObjectValue::Float(value) => Ok(Object::new_float(-value)),
-        _ => Err(String::from(
-            "Invalid prefix minus expression to evaluate non-numeric value",
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Invalid prefix minus expression to evaluate non-numeric value"),
         )),
     }
 }
 
-fn eval_infix_expression(operator: String, left: Object, right: Object) -> Result<Object, String> {
-    if let ObjectValue::Integer(left) = left.value()
+fn eval_logical_expression(
+    operator: String,
+    left: Node,
+    right: Node,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let left = eval(left, env)?;
+    match operator.as_str() {
+        "🔁" if !is_truthy(&left) => Ok(left),
+        "🔀" if is_truthy(&left) => Ok(left),
+        "❔❔" if *left.value() != ObjectValue::Null => Ok(left),
+        _ => eval(right, env),
+    }
+}
+
+fn eval_infix_expression(operator: String, left: Object, right: Object) -> Result<Object, EvalError> {
+    if operator == "📥" {
+        eval_membership_expression(left, right)
+    } else if let ObjectValue::Integer(left) = left.value()
         && let ObjectValue::Integer(right) = right.value()
     {
         eval_integer_infix_expression(operator, *left, *right)
@@ -241,40 +593,72 @@ fn eval_infix_expression(operator: String, left: Object, right: Object) -> Resul
         && let ObjectValue::List(right) = right.value()
     {
         eval_list_infix_expression(operator, left, right)
+    } else if let ObjectValue::Set(left) = left.value()
+        && let ObjectValue::Set(right) = right.value()
+    {
+        eval_set_infix_expression(operator, left, right)
+    } else if let Some(method) = operator_method(&left, &operator) {
+        apply_function(method, vec![left, right], vec![])
+    } else if operator == "❗🟰"
+        && let Some(method) = operator_method(&left, "🟰")
+    {
+        let equal = apply_function(method, vec![left, right], vec![])?;
+        Ok(Object::new_boolean(!is_truthy(&equal)))
     } else if operator == "🟰" {
         Ok(Object::new_boolean(left == right))
     } else if operator == "❗🟰" {
         Ok(Object::new_boolean(left != right))
     } else {
-        Err(format!(
-            "Invalid infix expression: {:?} {} {:?}",
-            left, operator, right
+        Err(EvalError::new(
+            TYPE_ERROR,
+            format!("Invalid infix expression: {:?} {} {:?}", left, operator, right),
         ))
     }
 }
 
+// class methods or associated functions named by the operator, e.g. 📛 ➕ 🌜🈯🦶 🅾️🌛
+fn operator_method(object: &Object, operator: &str) -> Option<Object> {
+    lookup_method(object, operator).or_else(|| {
+        object
+            .associated_env()
+            .get(&operator.to_string())
+            .map(|method| method.borrow().clone())
+    })
+}
+
 fn eval_integer_infix_expression(
     operator: String,
     left: i64,
     right: i64,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
+    let arithmetic = |result: Option<i64>| {
+        result.map(Object::new_integer).ok_or_else(|| match operator.as_str() {
+            "➗" | "〰️" if right == 0 => {
+                EvalError::new(ZERO_DIVISION_ERROR, format!("Cannot divide {left} by zero"))
+            }
+            _ => EvalError::new(
+                OVERFLOW_ERROR,
+                format!("Integer overflow evaluating {left} {operator} {right}"),
+            ),
+        })
+    };
     match operator.as_str() {
-        "➕" => Ok(Object::new_integer(left + right)),
-        "➖" => Ok(Object::new_integer(left - right)),
-        "✖️" => Ok(Object::new_integer(left * right)),
-        "➗" => Ok(Object::new_integer(left / right)),
-        "〰️" => Ok(Object::new_integer(left % right)),
+        "➕" => arithmetic(left.checked_add(right)),
+        "➖" => arithmetic(left.checked_sub(right)),
+        "✖️" => arithmetic(left.checked_mul(right)),
+        "➗" => arithmetic(left.checked_div(right)),
+        "〰️" => arithmetic(left.checked_rem(right)),
         "🟰" => Ok(Object::new_boolean(left == right)),
         "❗🟰" => Ok(Object::new_boolean(left != right)),
         "▶️" => Ok(Object::new_boolean(left > right)),
         "▶️🟰" => Ok(Object::new_boolean(left >= right)),
         "◀️" => Ok(Object::new_boolean(left < right)),
         "◀️🟰" => Ok(Object::new_boolean(left <= right)),
-        _ => Err(String::from("Invalid infix expression operator")),
+        _ => Err(String::from("Invalid infix expression operator").into()),
     }
 }
 
-fn eval_float_infix_expression(operator: String, left: f64, right: f64) -> Result<Object, String> {
+fn eval_float_infix_expression(operator: String, left: f64, right: f64) -> Result<Object, EvalError> {
     match operator.as_str() {
         "➕" => Ok(Object::new_float(left + right)),
         "➖" => Ok(Object::new_float(left - right)),
@@ -287,7 +671,7 @@ fn eval_float_infix_expression(operator: String, left: f64, right: f64) -> Resul
         "▶️🟰" => Ok(Object::new_boolean(left >= right)),
         "◀️" => Ok(Object::new_boolean(left < right)),
         "◀️🟰" => Ok(Object::new_boolean(left <= right)),
-        _ => Err(String::from("Invalid infix expression operator")),
+        _ => Err(String::from("Invalid infix expression operator").into()),
     }
 }
 
@@ -295,13 +679,11 @@ fn eval_boolean_infix_expression(
     operator: String,
     left: bool,
     right: bool,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     match operator.as_str() {
         "🟰" => Ok(Object::new_boolean(left == right)),
         "❗🟰" => Ok(Object::new_boolean(left != right)),
-        "🔁" => Ok(Object::new_boolean(left && right)),
-        "🔀" => Ok(Object::new_boolean(left || right)),
-        _ => Err(String::from("Invalid infix expression operator")),
+        _ => Err(String::from("Invalid infix expression operator").into()),
     }
 }
 
@@ -309,7 +691,7 @@ fn eval_string_infix_expression(
     operator: String,
     left: &str,
     right: &str,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     match operator.as_str() {
         "➕" => {
             let mut join = String::from(left);
@@ -318,7 +700,7 @@ fn eval_string_infix_expression(
         }
         "🟰" => Ok(Object::new_boolean(left == right)),
         "❗🟰" => Ok(Object::new_boolean(left != right)),
-        _ => Err(String::from("Invalid infix expression operator")),
+        _ => Err(String::from("Invalid infix expression operator").into()),
     }
 }
 
@@ -326,7 +708,7 @@ fn eval_list_infix_expression(
     operator: String,
     left: &Vec<Object>,
     right: &Vec<Object>,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     match operator.as_str() {
         "➕" => {
             let mut union = left.clone();
@@ -343,31 +725,95 @@ fn eval_list_infix_expression(
         }
         "🟰" => Ok(Object::new_boolean(left == right)),
         "❗🟰" => Ok(Object::new_boolean(left != right)),
-        _ => Err(String::from("Invalid infix expression operator")),
+        _ => Err(String::from("Invalid infix expression operator").into()),
     }
 }
 
-fn eval_index_expression(left: Object, index: Object) -> Result<Object, String> {
+// ➕ union, ✖️ intersection, ➖ difference, 〰️ symmetric difference, ◀️/▶️ (proper) subset/superset
+fn eval_set_infix_expression(
+    operator: String,
+    left: &IndexSet<Object>,
+    right: &IndexSet<Object>,
+) -> Result<Object, EvalError> {
+    match operator.as_str() {
+        "➕" => Ok(Object::new_set(left.union(right).cloned().collect())),
+        "✖️" => Ok(Object::new_set(left.intersection(right).cloned().collect())),
+        "➖" => Ok(Object::new_set(left.difference(right).cloned().collect())),
+        "〰️" => Ok(Object::new_set(left.symmetric_difference(right).cloned().collect())),
+        "◀️🟰" => Ok(Object::new_boolean(left.is_subset(right))),
+        "◀️" => Ok(Object::new_boolean(left.is_subset(right) && left != right)),
+        "▶️🟰" => Ok(Object::new_boolean(left.is_superset(right))),
+        "▶️" => Ok(Object::new_boolean(left.is_superset(right) && left != right)),
+        "🟰" => Ok(Object::new_boolean(left == right)),
+        "❗🟰" => Ok(Object::new_boolean(left != right)),
+        _ => Err(String::from("Invalid infix expression operator").into()),
+    }
+}
+
+fn eval_membership_expression(value: Object, collection: Object) -> Result<Object, EvalError> {
+    let contained = match (collection.value(), value.value()) {
+        (ObjectValue::Set(elements), _) => elements.contains(&value),
+        (ObjectValue::List(elements) | ObjectValue::Tuple(elements), _) => elements.contains(&value),
+        (ObjectValue::Map(entries), _) => entries.contains_key(&value),
+        (ObjectValue::String(string), ObjectValue::String(substring)) => string.contains(substring.as_str()),
+        _ => {
+            return Err(EvalError::new(
+                TYPE_ERROR,
+                format!("Invalid membership test: {:?} 📥 {:?}", value, collection),
+            ))
+        }
+    };
+    Ok(Object::new_boolean(contained))
+}
+
+fn eval_index_expression(left: Object, index: Object) -> Result<Object, EvalError> {
     match left.value() {
-        ObjectValue::List(elements) => {
+        ObjectValue::List(elements) | ObjectValue::Tuple(elements) => {
             if let ObjectValue::Integer(index) = index.value()
                 && *index >= 0
             {
                 elements
                     .get(*index as usize)
                     .cloned()
-                    .ok_or_else(|| format!("Invalid index: {index}"))
+                    .ok_or_else(|| EvalError::new(INDEX_ERROR, format!("Invalid index: {index}")))
             } else {
-                Err(String::from(
-                    "Index must be an integer greater than or equal to 0",
+                Err(EvalError::new(
+                    INDEX_ERROR,
+                    String::from("Index must be an integer greater than or equal to 0"),
                 ))
             }
         }
         ObjectValue::Map(entries) => entries
             .get(&index)
             .cloned()
-            .ok_or_else(|| format!("Invalid index: {index:?}")),
-        _ => Err(String::from("Invalid collection type to index")),
+            .ok_or_else(|| EvalError::new(INDEX_ERROR, format!("Invalid index: {}", index.inspect()))),
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            String::from("Invalid collection type to index"),
+        )),
+    }
+}
+
+fn eval_optional_index_expression(
+    left: Object,
+    index: Node,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    if *left.value() == ObjectValue::Null {
+        return Ok(Object::new_null());
+    }
+    let index = eval(index, env)?;
+    match (left.value(), index.value()) {
+        (ObjectValue::List(elements) | ObjectValue::Tuple(elements), ObjectValue::Integer(index)) => Ok(usize::try_from(*index)
+            .ok()
+            .and_then(|index| elements.get(index))
+            .cloned()
+            .unwrap_or_else(Object::new_null)),
+        (ObjectValue::Map(entries), _) => Ok(entries
+            .get(&index)
+            .cloned()
+            .unwrap_or_else(Object::new_null)),
+        _ => eval_index_expression(left, index),
     }
 }
 
@@ -376,7 +822,7 @@ fn eval_if_expression(
     consequence: Node,
     alternative: Option<Box<Node>>,
     env: &mut Environment,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     if eval_condition(condition, env)? {
         eval(consequence, env)
     } else if let Some(alternative) = alternative {
@@ -390,7 +836,7 @@ fn eval_while_expression(
     condition: Node,
     body: Node,
     env: &mut Environment,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     while eval_condition(condition.clone(), env)? {
         eval(body.clone(), env)?;
     }
@@ -400,7 +846,7 @@ fn eval_while_expression(
 fn eval_break_expression(
     _break_value: Option<Box<Node>>,
     _env: &mut Environment,
-) -> Result<Object, String> {
+) -> Result<Object, EvalError> {
     // let value = if let Some(value) = break_value {
     //     Some(Box::new(eval(*value, env)?))
     // } else {
@@ -410,87 +856,768 @@ fn eval_break_expression(
     Ok(Object::new_null())
 }
 
-fn eval_condition(condition: Node, env: &mut Environment) -> Result<bool, String> {
-    Ok(match eval(condition, env)?.value() {
+fn eval_declare_expression(
+    mutable: bool,
+    identifier: Node,
+    value: Node,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let value_object = eval(value, env)?;
+    let mut bindings = vec![];
+    match_pattern(&identifier, &value_object, env, &mut bindings, true)?;
+    for (identifier, value) in bindings {
+        env.declare(identifier, value, mutable)?;
+    }
+    Ok(value_object)
+}
+
+fn eval_try_expression(
+    body: Node,
+    error: Option<Box<Node>>,
+    handler: Option<Box<Node>>,
+    finalizer: Option<Box<Node>>,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let mut result = eval(body, env);
+    if let Err(EvalError::Exception(exception)) = &result
+        && let Some(handler) = handler
+    {
+        if let Some(error) = error {
+            env.assign(error.string(), *exception.clone())?;
+        }
+        result = eval(*handler, env);
+    }
+
+    if let Some(finalizer) = finalizer {
+        // a 🔙 in the 🏁 block overrides the result of the others
+        let finalized = eval(*finalizer, env)?;
+        if let ObjectValue::ReturnValue(_) = finalized.value() {
+            return Ok(finalized);
+        }
+    }
+    result
+}
+
+fn eval_throw_expression(value: Object) -> EvalError {
+    match value.value() {
+        ObjectValue::Error { .. } => EvalError::Exception(Box::new(value)),
+        ObjectValue::String(message) => EvalError::new(GENERIC_ERROR, message.clone()),
+        _ => EvalError::new(
+            TYPE_ERROR,
+            format!("Expected an error or a string to 🚨, but got {}", value.inspect()),
+        ),
+    }
+}
+
+fn eval_class_declaration(
+    name: Node,
+    parent: Option<Box<Node>>,
+    methods: Vec<Node>,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let parent = match parent {
+        Some(parent) => match eval(*parent.clone(), env)?.value() {
+            ObjectValue::Class(class) => Some(class.clone()),
+            _ => {
+                return Err(EvalError::new(
+                    TYPE_ERROR,
+                    format!("Expected a class to 🧬 from, but got {}", parent.string()),
+                ));
+            }
+        },
+        None => None,
+    };
+
+    // methods are closures over the declaring scope, with 🦸 bound to the parent class
+    let mut method_env = Environment::new_enclosed(Box::new(env.clone()));
+    if let Some(parent) = &parent {
+        method_env.set_constant(String::from("🦸"), Object::new_class(parent.clone()));
+    }
+    let mut class_methods = HashMap::new();
+    for method in methods {
+        let method = eval(method, &mut method_env)?;
+        if let ObjectValue::Function { name: Some(name), .. } = method.value() {
+            class_methods.insert(name.clone(), method.clone());
+        }
+    }
+
+    let class = Object::new_class(Rc::new(Class {
+        name: name.string(),
+        parent,
+        methods: class_methods,
+    }));
+    env.assign(name.string(), class.clone())?;
+    Ok(class)
+}
+
+fn eval_propagate_expression(value: Object) -> Result<Object, EvalError> {
+    match value.value() {
+        ObjectValue::Result(Ok(value)) => Ok(*value.clone()),
+        ObjectValue::Result(Err(_)) => Err(EvalError::Propagate(Box::new(value))),
+        _ => Err(EvalError::new(
+            TYPE_ERROR,
+            format!("Expected a 🆗 or 🆖 result before ⁉️, but got {}", value.inspect()),
+        )),
+    }
+}
+
+fn eval_match_expression(
+    subject: Node,
+    arms: Vec<(Node, Option<Node>, Node)>,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let subject = eval(subject, env)?;
+    for (pattern, guard, body) in arms {
+        let mut bindings = vec![];
+        if !match_pattern(&pattern, &subject, env, &mut bindings, false)? {
+            continue;
+        }
+        // the bindings of an arm live in their own scope, so they never touch the outer variables
+        let mut arm_env = Environment::new_enclosed(Box::new(env.clone()));
+        for (identifier, value) in bindings {
+            arm_env.set(identifier, value);
+        }
+        if let Some(guard) = guard
+            && !eval_condition(guard, &mut arm_env)?
+        {
+            continue;
+        }
+        return eval(body, &mut arm_env);
+    }
+    Err(format!(
+        "Non-exhaustive match: no arm matches {}",
+        subject.inspect()
+    ).into())
+}
+
+fn match_pattern(
+    pattern: &Node,
+    object: &Object,
+    env: &mut Environment,
+    bindings: &mut Vec<(String, Object)>,
+    strict: bool,
+) -> Result<bool, EvalError> {
+    if let Some((ok, inner_pattern)) = result_pattern(pattern) {
+        return match object.value() {
+            ObjectValue::Result(Ok(value)) if ok => {
+                match_pattern(inner_pattern, value, env, bindings, strict)
+            }
+            ObjectValue::Result(Err(value)) if !ok => {
+                match_pattern(inner_pattern, value, env, bindings, strict)
+            }
+            _ => pattern_mismatch(strict, || {
+                format!("{} does not match pattern {}", object.inspect(), pattern.string())
+            }),
+        };
+    }
+    if let Some(matched) = match_variant_pattern(pattern, object, env, bindings, strict)? {
+        return Ok(matched);
+    }
+    match pattern {
+        Node::Wildcard => Ok(true),
+        Node::Identifier { value } => {
+            bindings.push((value.clone(), object.clone()));
+            Ok(true)
+        }
+        Node::ListLiteral { elements } => match object.value() {
+            ObjectValue::List(values) => {
+                match_list_pattern(elements, values, Object::new_list, env, bindings, strict)
+            }
+            _ => pattern_mismatch(strict, || {
+                format!("Cannot destructure {} as a list", object.inspect())
+            }),
+        },
+        Node::TupleLiteral { elements } => match object.value() {
+            ObjectValue::Tuple(values) => {
+                match_list_pattern(elements, values, Object::new_tuple, env, bindings, strict)
+            }
+            _ => pattern_mismatch(strict, || {
+                format!("Cannot destructure {} as a tuple", object.inspect())
+            }),
+        },
+        Node::MapLiteral { entries } => match object.value() {
+            ObjectValue::Map(values) => match_map_pattern(entries, values, env, bindings, strict),
+            _ => pattern_mismatch(strict, || {
+                format!("Cannot destructure {} as a map", object.inspect())
+            }),
+        },
+        Node::SpreadElement { .. } => Err(format!(
+            "Unexpected {} outside of a list/tuple/map pattern",
+            pattern.string()
+        ).into()),
+        _ => {
+            if eval(pattern.clone(), env)? == *object {
+                Ok(true)
+            } else {
+                pattern_mismatch(strict, || {
+                    format!("{} does not match pattern {}", object.inspect(), pattern.string())
+                })
+            }
+        }
+    }
+}
+
+// 🅴❇️🆅🌜patterns🌛 destructures the fields of a variant in declaration order,
+// returns None if the pattern is not a variant pattern
+fn match_variant_pattern(
+    pattern: &Node,
+    object: &Object,
+    env: &mut Environment,
+    bindings: &mut Vec<(String, Object)>,
+    strict: bool,
+) -> Result<Option<bool>, EvalError> {
+    let Node::MemberExpression {
+        instance, member, ..
+    } = pattern
+    else {
+        return Ok(None);
+    };
+    let Node::CallExpression {
+        function,
+        arguments,
+    } = member.as_ref()
+    else {
+        return Ok(None);
+    };
+    let enum_type = eval(*instance.clone(), env)?;
+    let ObjectValue::EnumType(enum_type) = enum_type.value() else {
+        return Ok(None);
+    };
+
+    let variant = function.string();
+    if let ObjectValue::Variant {
+        enum_name,
+        name,
+        fields: Some(fields),
+    } = object.value()
+        && *enum_name == enum_type.name
+        && *name == variant
+    {
+        if fields.len() != arguments.len() {
+            return Err(format!(
+                "Expected {} field pattern(s) for variant {}❇️{}, but got {}",
+                fields.len(),
+                enum_name,
+                name,
+                arguments.len()
+            )
+            .into());
+        }
+        for (argument, (_, value)) in arguments.iter().zip(fields) {
+            if !match_pattern(argument, value, env, bindings, strict)? {
+                return Ok(Some(false));
+            }
+        }
+        return Ok(Some(true));
+    }
+    pattern_mismatch(strict, || {
+        format!("{} does not match pattern {}", object.inspect(), pattern.string())
+    })
+    .map(Some)
+}
+
+// 🆗🌜pattern🌛 and 🆖🌜pattern🌛 destructure results
+fn result_pattern(pattern: &Node) -> Option<(bool, &Node)> {
+    if let Node::CallExpression {
+        function,
+        arguments,
+    } = pattern
+        && let Node::Identifier { value } = function.as_ref()
+        && let [argument] = arguments.as_slice()
+    {
+        match value.as_str() {
+            "🆗" => Some((true, argument)),
+            "🆖" => Some((false, argument)),
+            _ => None,
+        }
+    } else {
+        None
+    }
+}
+
+// also destructures tuples, the rest of the elements is collected by `new_rest`
+fn match_list_pattern(
+    elements: &[Node],
+    values: &[Object],
+    new_rest: fn(Vec<Object>) -> Object,
+    env: &mut Environment,
+    bindings: &mut Vec<(String, Object)>,
+    strict: bool,
+) -> Result<bool, EvalError> {
+    let rest_position = elements
+        .iter()
+        .position(|element| matches!(element, Node::SpreadElement { .. }));
+    let (before, after) = match rest_position {
+        Some(position) => (&elements[..position], &elements[position + 1..]),
+        None => (elements, &elements[elements.len()..]),
+    };
+    if after
+        .iter()
+        .any(|element| matches!(element, Node::SpreadElement { .. }))
+    {
+        return Err(String::from("Expected at most one 🧺 in a list pattern").into());
+    }
+
+    let fixed_len = before.len() + after.len();
+    if values.len() < fixed_len {
+        return pattern_mismatch(strict, || {
+            format!(
+                "Missing element(s) to destructure: expected {}{} element(s), but got {}",
+                if rest_position.is_some() { "at least " } else { "" },
+                fixed_len,
+                values.len()
+            )
+        });
+    }
+    if rest_position.is_none() && values.len() != fixed_len {
+        return pattern_mismatch(strict, || {
+            format!(
+                "Too many elements to destructure: expected {} element(s), but got {}",
+                fixed_len,
+                values.len()
+            )
+        });
+    }
+
+    for (element, value) in before.iter().zip(values) {
+        if !match_pattern(element, value, env, bindings, strict)? {
+            return Ok(false);
+        }
+    }
+    for (element, value) in after.iter().zip(&values[values.len() - after.len()..]) {
+        if !match_pattern(element, value, env, bindings, strict)? {
+            return Ok(false);
+        }
+    }
+    if let Some(position) = rest_position
+        && let Node::SpreadElement { value } = &elements[position]
+    {
+        let rest = values[before.len()..values.len() - after.len()].to_vec();
+        return match_pattern(value, &new_rest(rest), env, bindings, strict);
+    }
+    Ok(true)
+}
+
+fn match_map_pattern(
+    entries: &[(Node, Node)],
+    values: &IndexMap<Object, Object>,
+    env: &mut Environment,
+    bindings: &mut Vec<(String, Object)>,
+    strict: bool,
+) -> Result<bool, EvalError> {
+    let mut rest = values.clone();
+    let mut rest_pattern = None;
+    for (key, pattern) in entries {
+        if let Node::SpreadElement { value } = key {
+            if rest_pattern.replace(value).is_some() {
+                return Err(String::from("Expected at most one 🧺 in a map pattern").into());
+            }
+            continue;
+        }
+        let key = eval(key.clone(), env)?;
+        if let Some(value) = values.get(&key) {
+            if !match_pattern(pattern, value, env, bindings, strict)? {
+                return Ok(false);
+            }
+            rest.shift_remove(&key);
+        } else {
+            return pattern_mismatch(strict, || {
+                format!("Missing key to destructure: {}", key.inspect())
+            });
+        }
+    }
+    if let Some(pattern) = rest_pattern {
+        return match_pattern(pattern, &Object::new_map(rest), env, bindings, strict);
+    }
+    Ok(true)
+}
+
+fn pattern_mismatch(strict: bool, message: impl FnOnce() -> String) -> Result<bool, EvalError> {
+    if strict { Err(message().into()) } else { Ok(false) }
+}
+
+fn eval_condition(condition: Node, env: &mut Environment) -> Result<bool, EvalError> {
+    Ok(is_truthy(&eval(condition, env)?))
+}
+
+// truthiness of conditions, ⏸️ and the operands of 🔁 and 🔀
+fn is_truthy(obj: &Object) -> bool {
+    match obj.value() {
+        ObjectValue::Integer(value) => *value != 0,
+        ObjectValue::Float(value) => *value != 0.0,
+        ObjectValue::Boolean(value) => *value,
+        ObjectValue::String(value) => !value.is_empty(),
         ObjectValue::Null => false,
-        ObjectValue::Boolean(boolean) => *boolean,
+        ObjectValue::List(value) | ObjectValue::Tuple(value) => !value.is_empty(),
+        ObjectValue::Map(value) => !value.is_empty(),
+        ObjectValue::Set(value) => !value.is_empty(),
+        ObjectValue::Reference(value) => is_truthy(&value.borrow()),
+        ObjectValue::ReturnValue(value) => is_truthy(value),
         _ => true,
-    })
+    }
 }
 
-fn eval_identifier(value: &String, env: &Environment) -> Result<Object, String> {
+fn eval_identifier(value: &String, env: &Environment) -> Result<Object, EvalError> {
     env.get(value)
-        .cloned()
-        .ok_or_else(|| format!("identifier not found: {value}"))
+        .map(|obj| obj.borrow().clone().dereference())
+        .ok_or_else(|| format!("identifier not found: {value}").into())
 }
 
-fn eval_expressions(arguments: Vec<Node>, env: &mut Environment) -> Result<Vec<Object>, String> {
+fn eval_arguments(
+    arguments: Vec<Node>,
+    env: &mut Environment,
+) -> Result<(Vec<Object>, NamedArguments), EvalError> {
     let mut args = vec![];
+    let mut named_args = vec![];
     for arg in arguments {
-        args.push(eval(arg, env)?);
+        if let Node::NamedArgument { name, value } = arg {
+            named_args.push((name.string(), eval_argument(*value, env)?));
+        } else {
+            args.push(eval_argument(arg, env)?);
+        }
     }
-    Ok(args)
+    Ok((args, named_args))
 }
 
-fn eval_member_expression(mut instance: Object, right: Node) -> Result<Object, String> {
-    let env = instance.associated_env_mut();
-    let right = if let Node::CallExpression {
-        function,
-        mut arguments,
-    } = right
+// variables are passed by reference, so updates in place through a parameter like 🅻👉0️⃣👈 ⬅️ 1️⃣
+// update the variable of the caller, while assigning to the parameter like 🅻 ⬅️ 1️⃣ only rebinds it
+fn eval_argument(argument: Node, env: &mut Environment) -> Result<Object, EvalError> {
+    if let Node::Identifier { value } = &argument
+        && !env.is_constant(value)
+        && let Some(storage) = env.get(value)
     {
-        let self_token = Token::this();
-        arguments.insert(
-            0,
-            Node::Identifier {
-                value: self_token.literal,
-            },
-        );
+        // parameters passed on refer to the variable of the original caller
+        return Ok(match storage.borrow().value() {
+            ObjectValue::Reference(_) => storage.borrow().clone(),
+            _ => Object::new_reference(storage.clone()),
+        });
+    }
+    eval(argument, env)
+}
 
+fn eval_member_expression(
+    mut instance: Object,
+    right: Node,
+    optional: bool,
+    env: &mut Environment,
+) -> Result<Object, EvalError> {
+    let (right, fallible) = fallible_method_call(right, &instance);
+    // fields and class methods take precedence over the associated environment
+    let name = direct_member_name(&right);
+    let field = name.and_then(|name| lookup_field(&instance, name));
+    let method = name.and_then(|name| lookup_method(&instance, name));
+    if optional
+        && (*instance.value() == ObjectValue::Null
+            || field.is_none()
+                && method.is_none()
+                && member_name(&right).is_none_or(|name| instance.associated_env().get(name).is_none()))
+    {
+        return Ok(Object::new_null());
+    }
+    match right {
         Node::CallExpression {
             function,
             arguments,
+        } => {
+            // the method is looked up on the instance, its arguments in the caller scope
+            let (mut args, named_args) = eval_arguments(arguments, env)?;
+            let function = match (field, method) {
+                (Some(field), _) => field,
+                (None, method) => {
+                    args.insert(0, eval_identifier(&Token::this().literal, instance.associated_env())?);
+                    match method {
+                        Some(method) => method,
+                        None => eval(*function, instance.associated_env_mut())?,
+                    }
+                }
+            };
+            match apply_function(function, args, named_args) {
+                Ok(value) if fallible => Ok(Object::new_result(Ok(value))),
+                Err(EvalError::Exception(error)) if fallible => Ok(Object::new_result(Err(*error))),
+                result => result,
+            }
         }
+        right => match field.or(method) {
+            Some(member) => Ok(member),
+            None => eval(right, instance.associated_env_mut()),
+        },
+    }
+}
+
+fn eval_super_member_expression(member: Node, env: &mut Environment) -> Result<Object, EvalError> {
+    let parent = env
+        .get(&String::from("🦸"))
+        .map(|parent| parent.borrow().clone())
+        .ok_or_else(|| String::from("Unexpected 🦸 outside of a method of a subclass"))?;
+    let ObjectValue::Class(parent) = parent.value() else {
+        unreachable!()
+    };
+    let name = direct_member_name(&member)
+        .ok_or_else(|| format!("Expected a method name after 🦸❇️, but got {}", member.string()))?;
+    let method = parent.method(name).cloned().ok_or_else(|| {
+        EvalError::new(TYPE_ERROR, format!("Class {} has no method {}", parent.name, name))
+    })?;
+
+    if let Node::CallExpression { arguments, .. } = member {
+        let (mut args, named_args) = eval_arguments(arguments, env)?;
+        args.insert(0, eval_identifier(&Token::this().literal, env)?);
+        apply_function(method, args, named_args)
     } else {
-        right
+        Ok(method)
+    }
+}
+
+// a call of an undefined method❇️🆗 calls the method, returning its value as 🆗 and its error as 🆖
+fn fallible_method_call(member: Node, instance: &Object) -> (Node, bool) {
+    let has_member = |name: &str| {
+        lookup_field(instance, name).is_some()
+            || lookup_method(instance, name).is_some()
+            || instance.associated_env().get(&name.to_string()).is_some()
     };
-    eval(right, env)
+    match member {
+        Node::CallExpression { function, arguments } => match *function {
+            Node::Identifier { value }
+                if !has_member(&value)
+                    && value.strip_suffix("🆗").is_some_and(|name| !name.is_empty() && has_member(name)) =>
+            {
+                let value = value.strip_suffix("🆗").unwrap().to_string();
+                let function = Box::new(Node::Identifier { value });
+                (Node::CallExpression { function, arguments }, true)
+            }
+            function => (Node::CallExpression { function: Box::new(function), arguments }, false),
+        },
+        member => (member, false),
+    }
+}
+
+// the member named by an identifier or by the function of a call
+fn direct_member_name(member: &Node) -> Option<&String> {
+    match member {
+        Node::Identifier { value } => Some(value),
+        Node::CallExpression { function, .. } => match function.as_ref() {
+            Node::Identifier { value } => Some(value),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn lookup_field(instance: &Object, name: &str) -> Option<Object> {
+    let find = |fields: &[(String, Object)]| {
+        fields
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value.clone())
+    };
+    match instance.value() {
+        ObjectValue::Record { fields, .. } => find(fields),
+        ObjectValue::Instance { fields, .. } => find(&fields.borrow()),
+        ObjectValue::Variant {
+            fields: Some(fields),
+            ..
+        } => find(fields),
+        // variants of an enum type: unit variants are values, the others construct one
+        ObjectValue::EnumType(enum_type) => match enum_type.variant(name)? {
+            (_, None) => Some(Object::new_variant(
+                enum_type.name.clone(),
+                name.to_string(),
+                None,
+            )),
+            (_, Some(_)) => Some(Object::new_variant_constructor(
+                enum_type.clone(),
+                name.to_string(),
+            )),
+        },
+        _ => None,
+    }
+}
+
+fn lookup_method(instance: &Object, name: &str) -> Option<Object> {
+    match instance.value() {
+        ObjectValue::Instance { class, .. } => class.method(name).cloned(),
+        _ => None,
+    }
+}
+
+fn member_name(member: &Node) -> Option<&String> {
+    match member {
+        Node::Identifier { value } => Some(value),
+        Node::CallExpression { function, .. } => member_name(function),
+        Node::IndexExpression { collection, .. } => member_name(collection),
+        _ => None,
+    }
 }
 
-fn apply_function(function: Object, args: Vec<Object>) -> Result<Object, String> {
+fn apply_function(
+    function: Object,
+    args: Vec<Object>,
+    named_args: NamedArguments,
+) -> Result<Object, EvalError> {
+    let (args, named_args) = if let ObjectValue::Function { .. } = function.value() {
+        (args, named_args)
+    } else {
+        (
+            args.into_iter().map(Object::dereference).collect(),
+            named_args
+                .into_iter()
+                .map(|(name, arg)| (name, arg.dereference()))
+                .collect(),
+        )
+    };
     match function.value() {
         ObjectValue::Function {
+            name,
             parameters,
             body,
             env,
+            ..
         } => {
-            if parameters.len() != args.len() {
-                return Err(format!(
-                    "Expected {} argument(s), but got {}",
-                    parameters.len(),
-                    args.len()
-                ));
-            }
+            let function_name = name.as_ref().map_or(String::from("anonymous function"), |name| {
+                format!("function {name}")
+            });
             let mut env = Environment::new_enclosed(env.clone());
-            for (index, param) in parameters.iter().enumerate() {
-                if let Node::Identifier { value } = param {
-                    env.set(value.clone(), args.get(index).unwrap().clone());
-                } else {
-                    return Err(format!("Not a identifier: {}", param.string()));
-                }
-            }
-            let return_val = eval(*body.clone(), &mut env)?;
+            bind_arguments(&function_name, parameters, args, named_args, &mut env)?;
+            let return_val = match eval(*body.clone(), &mut env) {
+                Err(EvalError::Propagate(result)) => *result,
+                return_val => return_val?,
+            };
             if let ObjectValue::ReturnValue(value) = return_val.value() {
                 Ok(*value.clone())
             } else {
                 Ok(return_val)
             }
         }
-        ObjectValue::BuiltinFunction(function) => function.call(&args),
-        _ => Err(format!("Not a function: {}", function.inspect())),
+        ObjectValue::BuiltinFunction(function) => {
+            if let Some((name, _)) = named_args.first() {
+                return Err(format!(
+                    "Unexpected named argument {} for builtin function {}",
+                    name,
+                    function.name()
+                ).into());
+            }
+            Ok(function.call(&args)?)
+        }
+        ObjectValue::RecordType { name, fields, env } => {
+            let fields = bind_fields(&format!("record {name}"), fields, args, named_args, env)?;
+            Ok(Object::new_record(name.clone(), fields))
+        }
+        ObjectValue::VariantConstructor { enum_type, name } => {
+            let fields = enum_type.fields(name).unwrap();
+            let variant_name = format!("variant {}❇️{}", enum_type.name, name);
+            let fields = bind_fields(&variant_name, fields, args, named_args, &enum_type.env)?;
+            Ok(Object::new_variant(enum_type.name.clone(), name.clone(), Some(fields)))
+        }
+        ObjectValue::Class(class) => {
+            let instance = Object::new_instance(class.clone());
+            match class.method(&Token::constructor().literal) {
+                Some(constructor) => {
+                    let mut args = args;
+                    args.insert(0, instance.clone());
+                    apply_function(constructor.clone(), args, named_args)?;
+                }
+                None if !args.is_empty() || !named_args.is_empty() => {
+                    return Err(format!(
+                        "Too many arguments for class {}: expected at most 0, but got {}",
+                        class.name,
+                        args.len() + named_args.len()
+                    )
+                    .into());
+                }
+                None => {}
+            }
+            Ok(instance)
+        }
+        _ => Err(EvalError::new(TYPE_ERROR, format!("Not a function: {}", function.inspect()))),
     }
 }
 
+// binds the constructor arguments of records and variants to their declared fields
+fn bind_fields(
+    type_name: &str,
+    fields: &[Node],
+    args: Vec<Object>,
+    named_args: NamedArguments,
+    env: &Environment,
+) -> Result<Vec<(String, Object)>, EvalError> {
+    let mut env = Environment::new_enclosed(Box::new(env.clone()));
+    bind_arguments(type_name, fields, args, named_args, &mut env)?;
+    fields
+        .iter()
+        .map(|field| {
+            let name = match field {
+                Node::AssignExpression { identifier, .. } => identifier.string(),
+                _ => field.string(),
+            };
+            let value = eval_identifier(&name, &env)?;
+            Ok((name, value))
+        })
+        .collect()
+}
+
+fn bind_arguments(
+    function_name: &str,
+    parameters: &[Node],
+    args: Vec<Object>,
+    mut named_args: NamedArguments,
+    env: &mut Environment,
+) -> Result<(), EvalError> {
+    let has_rest = parameters
+        .iter()
+        .any(|param| matches!(param, Node::SpreadElement { .. }));
+    let max_args = parameters.len() - usize::from(has_rest);
+    if !has_rest && args.len() > max_args {
+        return Err(format!(
+            "Too many arguments for {}: expected at most {}, but got {}",
+            function_name,
+            max_args,
+            args.len()
+        ).into());
+    }
+
+    let mut args = args.into_iter();
+    for param in parameters {
+        let (identifier, default) = match param {
+            Node::Identifier { value } => (value.clone(), None),
+            Node::AssignExpression {
+                identifier, value, ..
+            } => (identifier.string(), Some(value)),
+            Node::SpreadElement { value } => {
+                env.set(value.string(), Object::new_list(args.by_ref().map(Object::dereference).collect()));
+                continue;
+            }
+            _ => return Err(format!("Not a identifier: {}", param.string()).into()),
+        };
+        let named_arg = named_args
+            .iter()
+            .position(|(name, _)| *name == identifier)
+            .map(|index| named_args.remove(index).1);
+        let arg = match (args.next(), named_arg) {
+            (Some(_), Some(_)) => {
+                return Err(format!(
+                    "Multiple values for argument {identifier} of {function_name}"
+                ).into());
+            }
+            (Some(arg), None) | (None, Some(arg)) => arg,
+            (None, None) => match default {
+                Some(default) => eval(*default.clone(), env)?,
+                None => {
+                    return Err(format!(
+                        "Missing argument {identifier} for {function_name}"
+                    ).into());
+                }
+            },
+        };
+        env.set(identifier, arg);
+    }
+
+    if let Some((name, _)) = named_args.first() {
+        return Err(format!(
+            "Unexpected named argument {name} for {function_name}"
+        ).into());
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod evaluator_test {
     use crate::{lexer::Lexer, parser::Parser};
@@ -528,4 +1655,542 @@ mod evaluator_test {
         assert!(evaluated.is_ok());
         assert_eq!(evaluated.unwrap(), Object::new_integer(121));
     }
+
+    fn eval_source(source: &str) -> Result<Object, String> {
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+        assert!(parser.errors().is_empty(), "{:?}", parser.errors());
+        eval(program, &mut Environment::new_default()).map_err(|error| error.to_string())
+    }
+
+    fn eval_error(source: &str) -> String {
+        eval_source(source).expect_err(source)
+    }
+
+    #[test]
+    fn test_short_circuit() {
+        let source = "
+        🅰️ ⬅️ 👉👈
+        🅱️ ⬅️ 🅰️ 🔁 🅰️👉0️⃣👈
+        🅲 ⬅️ 0️⃣ 🔀 🗨️💬 🔀 🗨️🆗💬
+        🅳 ⬅️ 1️⃣ 🔁 ❌ 🔀 🗨️🆖💬
+        👉🅱️🦶 🅲🦶 🅳👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_list(vec![
+                Object::new_list(vec![]),
+                Object::new_string(String::from("🆗")),
+                Object::new_string(String::from("🆖")),
+            ])
+        );
+
+        // conditions, ⏸️ and the operands of 🔁 and 🔀 share the same truthiness
+        let source = "
+        🅸 ⬅️ 3️⃣
+        ⭕ 🅸 🫸 🅸 ➖⬅️ 1️⃣ 🫷
+        👉❓ 0️⃣ 🔁 ✔️ 🫸 1️⃣ 🫷 ❗ 🫸 2️⃣ 🫷🦶 ⏸️ ➖1️⃣🦶 ➖1️⃣ 🔁 🗨️x💬🦶 ⏸️ 👉👈 🟰 ⏸️ 🌜👉👈 🔀 ❌🌛🦶 🅸👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[2, false, \"x\", true, 0]");
+    }
+
+    #[test]
+    fn test_optional_access() {
+        let source = "
+        🅰️ ⬅️ 🫸 🗨️🅰️💬 ➡️ 👉1️⃣👈 🫷
+        🅱️ ⬅️ 🅰️❔👉🗨️🅱️💬👈
+        🅲 ⬅️ 🅰️❔👉🗨️🅰️💬👈❔👉5️⃣👈 ❔❔ 🅰️👉🗨️🅰️💬👈❔👉0️⃣👈
+        👉🅱️❔❇️📏🌜🌛 ❔❔ 0️⃣🦶 🅲🦶 🅰️❔❇️📏🌜🌛🦶 🅰️❔❇️🆖 ❔❔ 🅱️👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_list(vec![
+                Object::new_integer(0),
+                Object::new_integer(1),
+                Object::new_integer(1),
+                Object::new_null(),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_null_literal() {
+        let source = "
+        🅰️ ⬅️ 🈳
+        🅱️ ⬅️ ❓ 🅰️ 🟰 🈳 🫸 🗨️🈳💬 🫷 ❗ 🫸 🗨️🈶💬 🫷
+        👉🅰️🦶 🅱️🦶 🅰️ ❗🟰 🈳 🔁 🅰️❇️📏🌜🌛🦶 👁️‍🗨️🌜🈳🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_list(vec![
+                Object::new_null(),
+                Object::new_string(String::from("🈳")),
+                Object::new_boolean(false),
+                Object::new_string(String::from("🈳")),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_match_expression() {
+        let source = "
+        📛 🔍 🌜🅰️🌛 🫸
+          🎯 🅰️ 🫸
+            0️⃣ ➡️ 🗨️0️⃣💬
+            👉🅵🦶 🧺🆁👈 ➡️ 🅵 ➕ 🆁❇️📏🌜🌛
+            🫸 🗨️🅺💬 ➡️ 👉*️⃣🦶 🆅👈🦶 🧺🆁 🫷 ➡️ 🫸 🆅 ✖️ 🆁❇️📏🌜🌛 🫷
+            🅽 ❓ 🅽 ◀️ 0️⃣ ➡️ 🗨️➖💬
+            *️⃣ ➡️ 🈳
+          🫷
+        🫷
+        👉🔍🌜0️⃣🌛🦶 🔍🌜➖1️⃣🌛🦶 🔍🌜👉1️⃣🦶 2️⃣🦶 3️⃣👈🌛🦶 🔍🌜🫸🗨️🅺💬 ➡️ 👉0️⃣🦶 5️⃣👈🦶 1️⃣ ➡️ 1️⃣🫷🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_list(vec![
+                Object::new_string(String::from("0️⃣")),
+                Object::new_string(String::from("➖")),
+                Object::new_integer(3),
+                Object::new_integer(5),
+            ])
+        );
+        assert_eq!(
+            eval_source("🎯 1️⃣ 🫸 2️⃣ ➡️ ✔️ 🫷").unwrap_err(),
+            "Non-exhaustive match: no arm matches 1"
+        );
+        let source = "
+        🅰️ ⬅️ 7️⃣
+        🔒 🅱️ ⬅️ 8️⃣
+        🅲 ⬅️ 🎯 5️⃣ 🫸
+          🅰️ ❓ 🅰️ ◀️ 0️⃣ ➡️ 🗨️➖💬
+          🅱️ ➡️ 🅱️ ➕ 🅰️
+        🫷
+        👉🅰️🦶 🅱️🦶 🅲👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[7, 8, 12]");
+    }
+
+    #[test]
+    fn test_destructuring_assign() {
+        let source = "
+        📛 🔢 🌜🌛 🫸 🔙 👉1️⃣🦶 👉2️⃣🦶 3️⃣👈🦶 4️⃣🦶 5️⃣👈 🫷
+        👉🅰️🦶 👉🅱️🦶 *️⃣👈🦶 🧺🆁👈 ⬅️ 🔢🌜🌛
+        🫸 🗨️🅺💬 ➡️ 🅺🦶 🧺🅼 🫷 ⬅️ 🫸 🗨️🅺💬 ➡️ 6️⃣🦶 🗨️🅻💬 ➡️ 7️⃣ 🫷
+        👉🅰️🦶 🅱️🦶 🆁🦶 🅺🦶 🅼👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[1, 2, [4, 5], 6, {\"🅻\": 7}]"
+        );
+        assert_eq!(
+            eval_source("👉🅰️🦶 🅱️🦶 🅲👈 ⬅️ 👉1️⃣🦶 2️⃣👈").unwrap_err(),
+            "Missing element(s) to destructure: expected 3 element(s), but got 2"
+        );
+        assert_eq!(
+            eval_source("🫸 🗨️🅺💬 ➡️ 🅺 🫷 ⬅️ 🫸🫷").unwrap_err(),
+            "Missing key to destructure: \"🅺\""
+        );
+    }
+
+    #[test]
+    fn test_function_parameters() {
+        let source = "
+        📛 🔢 🌜🅰️🦶 🅱️ ⬅️ 🅰️ ✖️ 2️⃣🦶 🧺🆁🌛 🫸 👉🅰️🦶 🅱️🦶 🆁👈 🫷
+        👉🔢🌜1️⃣🌛🦶 🔢🌜1️⃣🦶 🅱️ ➡️ 5️⃣🌛🦶 🔢🌜1️⃣🦶 2️⃣🦶 3️⃣🦶 4️⃣🌛🦶 🔢🌜🅱️ ➡️ 0️⃣🦶 🅰️ ➡️ 9️⃣🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[[1, 2, []], [1, 5, []], [1, 2, [3, 4]], [9, 0, []]]"
+        );
+        assert_eq!(
+            eval_source("📛 🔢 🌜🅰️🌛 🫸 🅰️ 🫷 ↙️ 🔢🌜1️⃣🦶 2️⃣🌛").unwrap_err(),
+            "Too many arguments for function 🔢: expected at most 1, but got 2"
+        );
+        assert_eq!(
+            eval_source("📛 🔢 🌜🅰️🦶 🅱️🌛 🫸 🅰️ 🫷 ↙️ 🔢🌜🅱️ ➡️ 1️⃣🌛").unwrap_err(),
+            "Missing argument 🅰️ for function 🔢"
+        );
+        assert_eq!(
+            eval_source("📛 🔢 🌜🅰️🌛 🫸 🅰️ 🫷 ↙️ 🔢🌜1️⃣🦶 🅰️ ➡️ 1️⃣🌛").unwrap_err(),
+            "Multiple values for argument 🅰️ of function 🔢"
+        );
+        assert_eq!(
+            eval_source("📛 🔢 🌜🅰️🌛 🫸 🅰️ 🫷 ↙️ 🔢🌜1️⃣🦶 🅲 ➡️ 1️⃣🌛").unwrap_err(),
+            "Unexpected named argument 🅲 for function 🔢"
+        );
+    }
+
+    #[test]
+    fn test_lambda() {
+        let source = "
+        📛 🗺️ 🌜🅻🦶 🅵🌛 🫸
+          🆁 ⬅️ 👉👈
+          🅸 ⬅️ 0️⃣
+          ⭕ 🅸 ◀️ 🅻❇️📏🌜🌛 🫸
+            🆁 ➕⬅️ 👉🅵🌜🅻👉🅸👈🦶 🅸🌛👈
+            🅸 ➕⬅️ 1️⃣
+          🫷
+          🆁
+        🫷
+        🗺️🌜👉1️⃣🦶 2️⃣🦶 3️⃣👈🦶 ⚡🅰️🦶 🅸 ➡️ 🅰️ ✖️ 🅰️ ➕ 🅸🌛 ➕ 🗺️🌜👉🗨️🅰️💬👈🦶 ⚡🧺🆇 ➡️ 🈳🌛
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[1, 5, 11, null]");
+    }
+
+    #[test]
+    fn test_declarations() {
+        let source = "
+        🔒 🅰️ ⬅️ 1️⃣
+        📝 👉🅱️🦶 🅲👈 ⬅️ 👉2️⃣🦶 3️⃣👈
+        🅱️ ➕⬅️ 🅰️
+        📛 🆙 🌜🌛 🫸 🔒 🅰️ ⬅️ 1️⃣0️⃣ ↙️ 🅰️ 🫷
+        👉🅰️🦶 🅱️🦶 🅲🦶 🆙🌜🌛👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[1, 3, 3, 10]");
+
+        assert_eq!(eval_error("🔒 🅰️ ⬅️ 1️⃣ ↙️ 🅰️ ⬅️ 2️⃣"), "Cannot assign to constant 🅰️");
+        assert_eq!(eval_error("🔒 🅰️ ⬅️ 👉1️⃣👈 ↙️ 🅰️👉0️⃣👈 ⬅️ 2️⃣"), "Cannot assign to constant 🅰️");
+        assert_eq!(eval_error("🔒 🅰️ ⬅️ 1️⃣ ↙️ 📝 🅰️ ⬅️ 2️⃣"), "Cannot redeclare constant 🅰️");
+        assert_eq!(eval_error("🖨️ ⬅️ 1️⃣"), "Cannot assign to constant 🖨️");
+        assert_eq!(eval_error("📛 🖨️ 🌜🌛 🫸 🫷"), "Cannot assign to constant 🖨️");
+        assert_eq!(eval_error("📛 🆙 🌜🌛 🫸 🖨️ ⬅️ 1️⃣ 🫷 ↙️ 🆙🌜🌛"), "Cannot assign to constant 🖨️");
+        assert_eq!(eval_error("🅰️ ⬅️ 👉👈 ↙️ 🅰️❇️📏 ⬅️ 1️⃣"), "Cannot assign to constant 📏");
+        assert_eq!(eval_error("🅰️ ⬅️ 👉👈 ↙️ 🅰️❇️🈯 ⬅️ 1️⃣"), "Cannot assign to constant 🈯");
+    }
+
+    #[test]
+    fn test_exceptions() {
+        let source = "
+        📝 🅻 ⬅️ 👉👈
+        🅰️ ⬅️ 🤞 🫸 👉1️⃣👈👉5️⃣👈 🫷 🥅 🅴 🫸 🅴❇️🏷️ 🫷 🏁 🫸 🅻 ➕⬅️ 👉🗨️🏁💬👈 🫷
+        🅱️ ⬅️ 🤞 🫸 🚨 💥🌜🗨️ValueError💬🦶 🗨️bad💬🌛 🫷 🥅 🅴 🫸 🅴❇️📨 🫷
+        🅲 ⬅️ 🤞 🫸 1️⃣ ➕ 🗨️x💬 🫷 🥅 🅴 🫸 🅴❇️🏷️ 🫷
+        🅳 ⬅️ 🤞 🫸 1️⃣ ➗ 0️⃣ 🫷 🥅 🅴 🫸 🅴❇️🏷️ 🫷
+        📛 🆙 🌜🌛 🫸 🤞 🫸 🚨 🗨️oops💬 🫷 🏁 🫸 🔙 2️⃣ 🫷 🫷
+        👉🅰️🦶 🅱️🦶 🅲🦶 🅳🦶 🆙🌜🌛🦶 🅻👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[\"IndexError\", \"bad\", \"TypeError\", \"ZeroDivisionError\", 2, [\"🏁\"]]"
+        );
+
+        assert_eq!(eval_error("🚨 💥🌜🗨️ValueError💬🦶 🗨️bad💬🌛"), "ValueError: bad");
+        assert_eq!(eval_error("🚨 1️⃣"), "TypeError: Expected an error or a string to 🚨, but got 1");
+        assert_eq!(eval_error("🤞 🫸 🚨 🗨️a💬 🫷 🥅 🫸 🚨 🗨️b💬 🫷"), "b");
+        assert_eq!(eval_error("🚨 🗨️a💬 ↙️ 1️⃣"), "a");
+        assert_eq!(eval_error("🤞 🫸 👉👈👉0️⃣👈 🫷 🏁 🫸 🈳 🫷"), "IndexError: Invalid index: 0");
+        assert_eq!(eval_error("1️⃣ 〰️ 0️⃣"), "ZeroDivisionError: Cannot divide 1 by zero");
+        assert_eq!(eval_error("9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣ ✖️ 9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣9️⃣"), "OverflowError: Integer overflow evaluating 99999999999 ✖️ 9999999999");
+    }
+
+    #[test]
+    fn test_results() {
+        let source = "
+        📛 🅿️ 🌜🅰️🦶 🅱️🌛 🫸 🅰️❇️💕🆗🌜🅱️🌛⁉️ ➕ 1️⃣ 🫷
+        🅰️ ⬅️ 🅿️🌜2️⃣🦶 3️⃣🌛
+        🅱️ ⬅️ 🅿️🌜2️⃣🦶 🗨️x💬🌛
+        📛 🆅 🌜🆁🌛 🫸 🎯 🆁 🫸 🆗🌜🆅🌛 ➡️ 🆅 ↙️ 🆖🌜🅴🌛 ➡️ 🅴❇️🏷️ 🫷 🫷
+        👉🅰️🦶 🆅🌜🅱️🌛🦶 🆅🌜🆗🌜🅰️🌛🌛🦶 🆗🌜1️⃣🌛⁉️🦶 🆖🌜2️⃣🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[9, \"Error\", 9, 1, err(2)]"
+        );
+        assert_eq!(eval_source("🆖🌜1️⃣🌛⁉️ ↙️ 2️⃣").unwrap().inspect(), "err(1)");
+        let source = "
+        🏛️ 📦 🫸 📛 🆙 🌜🈯🦶 🅸🌛 🫸 👉1️⃣👈👉🅸👈 🫷 🫷
+        👉📦🌜🌛❇️🆙🆗🌜0️⃣🌛🦶 📦🌜🌛❇️🆙🆗🌜3️⃣🌛🦶 👉👈❇️📏🆗🌜🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[ok(1), err(IndexError: Invalid index: 3), ok(0)]"
+        );
+        // propagation followed by member and index access, unlike optional chaining with ❔❇️ and ❔👉
+        assert_eq!(
+            eval_source("🅻 ⬅️ 🆗🌜👉1️⃣🦶 2️⃣👈🌛 ↙️ 👉🅻⁉️❇️📏🌜🌛🦶 🅻⁉️👉0️⃣👈🦶 🈳❔❇️📏🦶 🈳❔👉0️⃣👈👈").unwrap().inspect(),
+            "[2, 1, null, null]"
+        );
+        assert_eq!(
+            eval_source("1️⃣⁉️"),
+            Err(String::from("TypeError: Expected a 🆗 or 🆖 result before ⁉️, but got 1"))
+        );
+    }
+
+    #[test]
+    fn test_records() {
+        let source = "
+        🧱 🅿️ 🫸 🆇🦶 🆈 ⬅️ 🆇 ✖️ 2️⃣ 🫷
+        🅰️ ⬅️ 🅿️🌜1️⃣🌛
+        🅱️ ⬅️ 🅿️🌜🆈 ➡️ 5️⃣🦶 🆇 ➡️ 3️⃣🌛
+        🅱️❇️🆇 ➕⬅️ 🅰️❇️🆈
+        👉🅰️🦶 🅱️🦶 🅱️❇️🆇🦶 👁️‍🗨️🌜🅰️🌛🦶 🅰️ 🟰 🅿️🌜1️⃣🦶 2️⃣🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[🅿️(🆇: 1, 🆈: 2), 🅿️(🆇: 5, 🆈: 5), 5, \"🅿️🌜🆇 ➡️ 1️⃣🦶 🆈 ➡️ 2️⃣🌛\", true]"
+        );
+
+        assert_eq!(eval_error("🧱 🅿️ 🫸 🆇 🫷 ↙️ 🅿️🌜🌛"), "Missing argument 🆇 for record 🅿️");
+        assert_eq!(eval_error("🧱 🅿️ 🫸 🆇 🫷 ↙️ 🅰️ ⬅️ 🅿️🌜1️⃣🌛 ↙️ 🅰️❇️🆉 ⬅️ 1️⃣"), "TypeError: Record 🅿️ has no field 🆉");
+    }
+
+    #[test]
+    fn test_classes() {
+        let source = "
+        🏛️ 🐾 🫸
+          📛 🆕 🌜🈯🦶 🅽🌛 🫸 🈯❇️🅽 ⬅️ 🅽 🫷
+          📛 🗣️ 🌜🈯🌛 🫸 🈯❇️🅽 ➕ 🗨️: 💬 🫷
+          📛 🎂 🌜🈯🌛 🫸 🈯❇️🅰️ ⬅️ 🌜🈯❔❇️🅰️ ❔❔ 0️⃣🌛 ➕ 1️⃣ 🫷
+        🫷
+        🏛️ 🐶 🧬 🐾 🫸
+          📛 🗣️ 🌜🈯🌛 🫸 🦸❇️🗣️🌜🌛 ➕ 🗨️woof💬 🫷
+        🫷
+        🅳 ⬅️ 🐶🌜🗨️rex💬🌛
+        🅳❇️🎂🌜🌛
+        🅳❇️🎂🌜🌛
+        👉🅳❇️🗣️🌜🌛🦶 🅳❇️🅰️🦶 🧪🌜🅳🦶 🐾🌛🦶 🧪🌜🐾🌜🗨️x💬🌛🦶 🐶🌛🦶 🔖🌜🅳🌛🦶 🔖🌜1️⃣🌛🦶 🅳👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[\"rex: woof\", 2, true, false, \"🐶\", \"integer\", 🐶(🅽: \"rex\", 🅰️: 2)]"
+        );
+
+        assert_eq!(eval_error("🏛️ 🐾 🫸 🫷 ↙️ 🐾🌜1️⃣🌛"), "Too many arguments for class 🐾: expected at most 0, but got 1");
+        assert_eq!(eval_error("🏛️ 🐾 🫸 📛 🗣️ 🌜🈯🌛 🫸 🦸❇️🗣️🌜🌛 🫷 🫷 ↙️ 🐾🌜🌛❇️🗣️🌜🌛"), "Unexpected 🦸 outside of a method of a subclass");
+        assert_eq!(eval_error("🅰️ ⬅️ 1️⃣ ↙️ 🏛️ 🐶 🧬 🅰️ 🫸 🫷"), "TypeError: Expected a class to 🧬 from, but got 🅰️");
+    }
+
+    #[test]
+    fn test_cyclic_instances() {
+        let source = "
+        🏛️ 🅽 🫸 📛 🆕 🌜🈯🌛 🫸 🈯❇️🅿️ ⬅️ 🈯 🫷 🫷
+        🅰️ ⬅️ 🅽🌜🌛
+        🅱️ ⬅️ 🅽🌜🌛
+        🅼 ⬅️ 🫸 🅰️ ➡️ 1️⃣ 🫷
+        👉🅰️🦶 👁️‍🗨️🌜🅰️🌛🦶 🅰️ 🟰 🅰️❇️🅿️🦶 🅰️ 🟰 🅱️🦶 🅼👉🅰️❇️🅿️👈🦶 🅼❔👉🅱️👈👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[🅽(🅿️: <cycle>), \"🅽🌜🅿️ ➡️ <cycle>🌛\", true, false, 1, null]"
+        );
+    }
+
+    #[test]
+    fn test_enums() {
+        let source = "
+        🧩 🅂 🫸 🅸🦶 🆁🌜🅽🦶 🅼 ⬅️ 0️⃣🌛 🫷
+        📛 🅳 🌜🆂🌛 🫸 🎯 🆂 🫸 🅂❇️🅸 ➡️ 🗨️idle💬 ↙️ 🅂❇️🆁🌜🅽🦶 *️⃣🌛 ➡️ 🅽 🫷 🫷
+        🅰️ ⬅️ 🅂❇️🆁🌜🗨️go💬🌛
+        🅼 ⬅️ 🫸🅰️ ➡️ 1️⃣🫷
+        👉🅳🌜🅂❇️🅸🌛🦶 🅳🌜🅰️🌛🦶 🅰️❇️🅼🦶 🅰️ 🟰 🅂❇️🆁🌜🗨️go💬🦶 0️⃣🌛🦶 🅼👉🅂❇️🆁🌜🗨️go💬🌛👈🦶 🧪🌜🅰️🦶 🅂❇️🆁🌛🦶 🧪🌜🅂❇️🅸🦶 🅂🌛🦶 🅰️👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[\"idle\", \"go\", 0, true, 1, true, true, 🅂❇️🆁(🅽: \"go\", 🅼: 0)]"
+        );
+
+        assert_eq!(eval_error("🧩 🅂 🫸 🆁🌜🅽🌛 🫷 ↙️ 🅂❇️🆁🌜🌛"), "Missing argument 🅽 for variant 🅂❇️🆁");
+        assert_eq!(eval_error("🧩 🅂 🫸 🆁🌜🅽🌛 🫷 ↙️ 🅰️ ⬅️ 🅂❇️🆁🌜1️⃣🌛 ↙️ 🅰️❇️🅽 ⬅️ 2️⃣"), "TypeError: Cannot assign to field 🅽 of variant 🅂❇️🆁");
+        assert_eq!(eval_error("🧩 🅂 🫸 🆁🌜🅽🌛 🫷 ↙️ 🎯 🅂❇️🆁🌜1️⃣🌛 🫸 🅂❇️🆁🌜🅰️🦶 🅱️🌛 ➡️ 🅰️ 🫷"), "Expected 1 field pattern(s) for variant 🅂❇️🆁, but got 2");
+    }
+
+    #[test]
+    fn test_operator_methods() {
+        let source = "
+        🏛️ 💰 🫸
+          📛 🆕 🌜🈯🦶 🅲🌛 🫸 🈯❇️🅲 ⬅️ 🅲 🫷
+          📛 ➕ 🌜🈯🦶 🅾️🌛 🫸 🈯❇️🅲 ➕ 🅾️❇️🅲 🫷
+          📛 🟰 🌜🈯🦶 🅾️🌛 🫸 🈯❇️🅲 〰️ 1️⃣0️⃣0️⃣ 🟰 🅾️❇️🅲 〰️ 1️⃣0️⃣0️⃣ 🫷
+          📛 ◀️ 🌜🈯🦶 🅾️🌛 🫸 🈯❇️🅲 ◀️ 🅾️❇️🅲 🫷
+        🫷
+        🅰️ ⬅️ 💰🌜1️⃣5️⃣0️⃣🌛
+        🅱️ ⬅️ 💰🌜5️⃣0️⃣🌛
+        🆂 ⬅️ 🅰️
+        🆂 ➕⬅️ 🅱️
+        👉🆂🦶 🅰️ 🟰 🅱️🦶 🅰️ ❗🟰 🅱️🦶 🅱️ ◀️ 🅰️👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[200, true, false, true]");
+
+        let error = eval_source("🏛️ 💰 🫸 🫷 ↙️ 💰🌜🌛 ✖️ 💰🌜🌛").unwrap_err();
+        assert!(error.starts_with("TypeError: Invalid infix expression"), "{error}");
+    }
+
+    #[test]
+    fn test_sets() {
+        let source = "
+        🅰️ ⬅️ 🫸1️⃣🦶 2️⃣🦶 3️⃣🦶 2️⃣🫷
+        🅱️ ⬅️ 🎒🌜👉3️⃣🦶 4️⃣👈🌛
+        🅲 ⬅️ 👉🅰️❇️📏🌜🌛🦶 🅰️ ➕ 🅱️ 🟰 🫸1️⃣🦶 2️⃣🦶 3️⃣🦶 4️⃣🫷🦶 🅰️ ✖️ 🅱️🦶 🅰️ ➖ 🅱️ 🟰 🫸2️⃣🦶 1️⃣🫷🦶 🅰️ 〰️ 🅱️ 🟰 🫸1️⃣🦶 2️⃣🦶 4️⃣🫷🦶 🫸1️⃣🫷 ◀️ 🅰️🦶 🅰️ ▶️🟰 🅱️👈
+        🅲 ➕ 👉2️⃣ 📥 🅰️🦶 🅰️❇️🔍🌜5️⃣🌛🦶 🅱️❇️📌🌜5️⃣🌛❇️✂️🌜4️⃣🌛❇️📋🌜🌛❇️📏🌜🌛🦶 🫸 🫸1️⃣🦶 2️⃣🫷 ➡️ 🗨️🆂💬 🫷👉🫸2️⃣🦶 1️⃣🫷👈🦶 🎒🌜🌛🦶 🗨️🅱️💬 📥 🗨️🅰️🅱️🅲💬👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[3, true, {3}, true, true, true, false, true, false, 2, \"🆂\", set(), true]"
+        );
+    }
+
+    #[test]
+    fn test_tuples() {
+        let source = "
+        📛 🆚 🌜🅰️🦶 🅱️🌛 🫸 🌜🅰️ ➗ 🅱️🦶 🅰️ 〰️ 🅱️🌛 🫷
+        🌜🆀🦶 🆁🌛 ⬅️ 🆚🌜7️⃣🦶 2️⃣🌛
+        🅼 ⬅️ 🫸 🌜1️⃣🦶 2️⃣🌛 ➡️ 🗨️🅿️💬 🫷
+        📛 🅵 🌜🆃🌛 🫸 🎯 🆃 🫸 🌜0️⃣🦶 *️⃣🌛 ➡️ 🗨️zero💬 ↙️ 🌜🅰️🦶 🧺🆁🌛 ➡️ 🆁 🫷 🫷
+        👉🆀🦶 🆁🦶 🅼👉🌜1️⃣🦶 2️⃣🌛👈🦶 🌜4️⃣🦶 5️⃣🌛👉1️⃣👈🦶 🌜4️⃣🦶🌛❇️📏🌜🌛🦶 🅵🌜🌜0️⃣🦶 1️⃣🌛🌛🦶 🅵🌜🌜1️⃣🦶 2️⃣🦶 3️⃣🌛🌛🦶 🌜🌛🦶 👁️‍🗨️🌜🌜1️⃣🦶 🌜2️⃣🦶🌛🌛🌛👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[3, 1, \"🅿️\", 5, 1, \"zero\", (2, 3), (), \"🌜1️⃣🦶 🌜2️⃣🦶🌛🌛\"]"
+        );
+
+        let error = eval_source("🆃 ⬅️ 🌜1️⃣🦶 2️⃣🌛 ↙️ 🆃👉0️⃣👈 ⬅️ 3️⃣").unwrap_err();
+        assert_eq!(error, "TypeError: Cannot assign to an element of a tuple");
+    }
+
+    #[test]
+    fn test_map_order() {
+        let source = "
+        🅼 ⬅️ 🫸 🗨️🅲💬 ➡️ 3️⃣🦶 🗨️🅰️💬 ➡️ 1️⃣🦶 🗨️🅱️💬 ➡️ 2️⃣ 🫷
+        🅺 ⬅️ 🫸 🫸 🗨️🅰️💬 ➡️ 1️⃣🦶 🗨️🅱️💬 ➡️ 2️⃣ 🫷 ➡️ 🗨️🅼💬🦶 🫸3️⃣🦶 1️⃣🦶 2️⃣🫷 ➡️ 🗨️🆂💬 🫷
+        👉👁️‍🗨️🌜🅼🌛🦶 🅺👉🫸 🗨️🅱️💬 ➡️ 2️⃣🦶 🗨️🅰️💬 ➡️ 1️⃣ 🫷👈🦶 🅺👉🫸2️⃣🦶 3️⃣🦶 1️⃣🫷👈🦶 🅺👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[\"🫸🅲 ➡️ 3️⃣🦶 🅰️ ➡️ 1️⃣🦶 🅱️ ➡️ 2️⃣🫷\", \"🅼\", \"🆂\", \
+             {{\"🅰️\": 1, \"🅱️\": 2}: \"🅼\", {3, 1, 2}: \"🆂\"}]"
+        );
+    }
+
+    #[test]
+    fn test_index_assignment() {
+        let source = "
+        🅼 ⬅️ 🫸 🗨️🅰️💬 ➡️ 🫸 🗨️🆇💬 ➡️ 👉1️⃣🦶 2️⃣👈 🫷 🫷
+        🅼👉🗨️🅱️💬👈 ⬅️ 2️⃣
+        🅼👉🗨️🅰️💬👈👉🗨️🆈💬👈 ⬅️ 3️⃣
+        🅼👉🗨️🅰️💬👈👉🗨️🆇💬👈👉0️⃣👈 ➕⬅️ 5️⃣
+        🅳 ⬅️ 🗑️ 🅼👉🗨️🅰️💬👈👉🗨️🆇💬👈
+        👉🅼🦶 🅳🦶 🗨️🅱️💬 📥 🅼🦶 🅼❇️🔍🌜🗨️🅲💬🌛🦶 🗑️ 🅳👉0️⃣👈🦶 🅳👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[{\"🅰️\": {\"🆈\": 3}, \"🅱️\": 2}, [6, 2], true, false, 6, [2]]"
+        );
+
+        assert_eq!(
+            eval_source("🅼 ⬅️ 🫸🫷 ↙️ 🗑️ 🅼👉🗨️🅺💬👈"),
+            Err(String::from("IndexError: Invalid index: \"🅺\""))
+        );
+        assert_eq!(
+            eval_source("🅼 ⬅️ 🫸🫷 ↙️ 🅼👉1️⃣👈👉2️⃣👈 ⬅️ 3️⃣"),
+            Err(String::from("IndexError: Invalid index: 1"))
+        );
+    }
+
+    #[test]
+    fn test_nested_mutation() {
+        let source = "
+        🧱 🅿️ 🫸 🆇🦶 🅻 🫷
+        📛 🅵 🌜🅻🦶 🅼🦶 🅽🌛 🫸 🅻👉0️⃣👈👉1️⃣👈 ⬅️ 9️⃣ ↙️ 🅼👉🗨️🅺💬👈 ➕⬅️ 1️⃣ ↙️ 🅽❇️🆇 ⬅️ 2️⃣ 🫷
+        🅰️ ⬅️ 👉👉1️⃣🦶 2️⃣👈👈
+        🅱️ ⬅️ 🫸 🗨️🅺💬 ➡️ 1️⃣ 🫷
+        🅲 ⬅️ 🅿️🌜0️⃣🦶 👉👈🌛
+        🅵🌜🅰️🦶 🅱️🦶 🅲🌛
+        🅿️ ⬅️ 🅿️🌜0️⃣🦶 👉🅿️🌜0️⃣🦶 👉👈🌛👈🌛
+        🅿️❇️🅻👉0️⃣👈❇️🆇 ⬅️ 5️⃣
+        🅿️❇️🅻👉0️⃣👈❇️🅻 ➕⬅️ 👉3️⃣👈
+        👉🅰️🦶 🅱️🦶 🅲🦶 🅿️❇️🅻👉0️⃣👈👈
+        ";
+        assert_eq!(
+            eval_source(source).unwrap().inspect(),
+            "[[[1, 9]], {\"🅺\": 2}, 🅿️(🆇: 2, 🅻: []), 🅿️(🆇: 5, 🅻: [3])]"
+        );
+
+        // assigning to a parameter only rebinds it, even after an update in place
+        let source = "
+        📛 🅵 🌜🅺🦶 🅻🦶 🅼🌛 🫸 ⭕ 🅺 ▶️ 0️⃣ 🫸 🅺 ⬅️ 🅺 ➖ 1️⃣ 🫷 ↙️ 🅻👉0️⃣👈 ⬅️ 1️⃣ ↙️ 🅻 ⬅️ 🈳 ↙️ 🅼 ⬅️ 9️⃣ 🫷
+        📛 🆂 🌜🅰️🦶 🅱️🌛 🫸 🅰️ ⬅️ 9️⃣ ↙️ 🅱️ 🫷
+        🅺 ⬅️ 3️⃣
+        🅻 ⬅️ 👉0️⃣👈
+        🅵🌜🅺🦶 🅻🦶 🅺🌛
+        🆇 ⬅️ 1️⃣
+        👉🅺🦶 🅻🦶 🆂🌜🆇🦶 🆇🌛🦶 🆇👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[3, [1], 1, 1]");
+
+        // captures see both in-place updates and rebindings of the variable
+        let source = "
+        🅻 ⬅️ 👉1️⃣👈
+        🅵 ⬅️ ⚡ ➡️ 🅻
+        🅻👉0️⃣👈 ⬅️ 2️⃣
+        🅰️ ⬅️ 🅵🌜🌛
+        🅻 ⬅️ 👉3️⃣👈
+        👉🅰️🦶 🅵🌜🌛👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[[2], [3]]");
+
+        // constants are passed by value
+        let constant = eval_source("📛 🅵 🌜🅻🌛 🫸 🅻👉0️⃣👈 ⬅️ 2️⃣ 🫷 ↙️ 🔒 🅰️ ⬅️ 👉1️⃣👈 ↙️ 🅵🌜🅰️🌛 ↙️ 🅰️");
+        assert_eq!(constant.unwrap().inspect(), "[1]");
+    }
+
+    #[test]
+    fn test_pipe() {
+        let source = "
+        📛 🆙 🌜🅰️🦶 🅱️ ⬅️ 1️⃣🌛 🫸 🅰️ ➕ 🅱️ 🫷
+        3️⃣ ➕ 1️⃣ ⏩ 🆙 ⏩ 🆙🌜🅱️ ➡️ 5️⃣🌛 ⏩ 👁️‍🗨️ ⏩ ⚡🅰️ ➡️ 🅰️ ➕ 🗨️!💬
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "\"1️⃣0️⃣!\"");
+
+        // builtin methods, bare or on an instance
+        let source = "
+        🅰️ ⬅️ 🫸 1️⃣🦶 2️⃣ 🫷
+        👉🅰️ ⏩ 📏🦶 3️⃣ ⏩ 2️⃣❇️💕🦶 2️⃣ ⏩ 💕🌜3️⃣🌛🦶 2️⃣ ⏩ 🅰️❇️🔍👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[2, 8, 8, true]");
+    }
+
+    #[test]
+    fn test_string_interpolation() {
+        let source = "
+        🅰️ ⬅️ 🫸 🗨️🅺💬 ➡️ 👉1️⃣🦶 2️⃣👈 🫷
+        🗨️🪄🗨️🅺🪄💬 ➡️ 🪄🌜🅰️👉🗨️🅺💬👈🌛🦶 📏 ➡️ 🪄🌜🅰️❇️📏🌜🌛 ➕ 🌜1️⃣ ✖️ 2️⃣🌛🌛🪄🌜🗨️!💬🌛💬
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_string(String::from("🗨️🅺💬 ➡️ 👉1️⃣🦶 2️⃣👈🦶 📏 ➡️ 3️⃣!"))
+        );
+    }
+
+    #[test]
+    fn test_compound_assign() {
+        let source = "
+        🅰️ ⬅️ 1️⃣
+        🅰️ ➕⬅️ 9️⃣
+        🅰️ ✖️⬅️ 3️⃣
+        🅰️ ➖⬅️ 2️⃣
+        🅰️ ➗⬅️ 4️⃣
+        🅰️ 〰️⬅️ 4️⃣
+        🅱️ ⬅️ 👉1️⃣🦶 🗨️🅰️💬👈
+        🅱️👉0️⃣👈 ➕⬅️ 🅰️
+        🅱️👉1️⃣👈 ➕⬅️ 🗨️🅱️💬
+        🅱️
+        ";
+        assert_eq!(
+            eval_source(source).unwrap(),
+            Object::new_list(vec![
+                Object::new_integer(4),
+                Object::new_string(String::from("🅰️🅱️")),
+            ])
+        );
+
+        // the target is evaluated once
+        let source = "
+        🅸 ⬅️ 0️⃣
+        🅻 ⬅️ 👉1️⃣🦶 2️⃣🦶 3️⃣👈
+        🅻👉🌜🅸 ➕⬅️ 1️⃣🌛👈 ➕⬅️ 1️⃣0️⃣
+        👉🅻🦶 🅸👈
+        ";
+        assert_eq!(eval_source(source).unwrap().inspect(), "[[1, 12, 3], 1]");
+    }
 }
@@ -6,15 +6,21 @@ use crate::util::emoji_convert::{digital_emoji_to_char, dot_char};
 
 pub struct Lexer<'a> {
     chars: StatefulVector<&'a str>,
+    errors: Vec<String>,
 }
 
 impl<'a> Lexer<'a> {
     pub fn new(input: &'a str) -> Lexer<'a> {
         Lexer {
             chars: StatefulVector::from_vec(input.graphemes(true).collect::<Vec<&str>>()),
+            errors: vec![],
         }
     }
 
+    pub fn errors(&self) -> &Vec<String> {
+        &self.errors
+    }
+
     pub fn tokenize(&mut self) -> StatefulVector<Token> {
         let mut tokens = StatefulVector::<Token>::new();
         let start_token = Token::start();
@@ -25,15 +31,30 @@ impl<'a> Lexer<'a> {
         while let Some(char) = self.chars.to_next() {
             let token = match *char {
                 "#️⃣" => {
-                    self.skip_comment();
-                    continue;
+                    if let Some(token) = self.handle_comment() {
+                        token
+                    } else {
+                        continue;
+                    }
                 }
                 "⬅️" => Token::from_str(TokenType::Assign, char),
-                "➕" => Token::from_str(TokenType::Plus, char),
-                "➖" => Token::from_str(TokenType::Minus, char),
-                "✖️" => Token::from_str(TokenType::Multiply, char),
-                "➗" => Token::from_str(TokenType::Divide, char),
-                "〰️" => Token::from_str(TokenType::Modulo, char),
+                "➕" => self.handle_two_chars_token(TokenType::Plus, "⬅️", TokenType::PlusAssign),
+                "➖" => self.handle_two_chars_token(TokenType::Minus, "⬅️", TokenType::MinusAssign),
+                "✖️" => self.handle_two_chars_token(
+                    TokenType::Multiply,
+                    "⬅️",
+                    TokenType::MultiplyAssign,
+                ),
+                "➗" => self.handle_two_chars_token(
+                    TokenType::Divide,
+                    "⬅️",
+                    TokenType::DivideAssign,
+                ),
+                "〰️" => self.handle_two_chars_token(
+                    TokenType::Modulo,
+                    "⬅️",
+                    TokenType::ModuloAssign,
+                ),
                 "🟰" => Token::from_str(TokenType::Equal, char),
                 "▶️" => self.handle_two_chars_token(
                     TokenType::GreaterThan,
@@ -51,13 +72,30 @@ impl<'a> Lexer<'a> {
                 "↙️" => Token::from_str(TokenType::Semicolon, char),
                 "✔️" => Token::from_str(TokenType::True, char),
                 "❌" => Token::from_str(TokenType::False, char),
+                "🈳" => Token::from_str(TokenType::Null, char),
                 "❓" => Token::from_str(TokenType::If, char),
                 "❗" => self.handle_two_chars_token(TokenType::Else, "🟰", TokenType::NotEqual),
                 "⭕" => Token::from_str(TokenType::While, char),
                 "🔜" => Token::from_str(TokenType::Continue, char),
                 "🔚" => Token::from_str(TokenType::Break, char),
                 "📛" => Token::from_str(TokenType::Function, char),
+                "⚡" => Token::from_str(TokenType::Lambda, char),
+                "📝" => Token::from_str(TokenType::Let, char),
+                "🔒" => Token::from_str(TokenType::Const, char),
+                "🤞" => Token::from_str(TokenType::Try, char),
+                "🥅" => Token::from_str(TokenType::Catch, char),
+                "🏁" => Token::from_str(TokenType::Finally, char),
+                "🚨" => Token::from_str(TokenType::Throw, char),
+                "🧱" => Token::from_str(TokenType::Record, char),
+                "🏛️" => Token::from_str(TokenType::Class, char),
+                "🧬" => Token::from_str(TokenType::Inherits, char),
+                "🦸" => Token::from_str(TokenType::Super, char),
+                "🧩" => Token::from_str(TokenType::Enum, char),
+                "🗑️" => Token::from_str(TokenType::Delete, char),
                 "🔙" => Token::from_str(TokenType::Return, char),
+                "🎯" => Token::from_str(TokenType::Match, char),
+                "*️⃣" => Token::from_str(TokenType::Wildcard, char),
+                "🧺" => Token::from_str(TokenType::Spread, char),
                 "➡️" => Token::from_str(TokenType::Describe, char),
                 "❇️" => Token::from_str(TokenType::Member, char),
                 "🦶" => Token::from_str(TokenType::Comma, char),
@@ -67,7 +105,12 @@ impl<'a> Lexer<'a> {
                 "👈" => Token::from_str(TokenType::RBracket, char),
                 "🫸" => Token::from_str(TokenType::LBrace, char),
                 "🫷" => Token::from_str(TokenType::RBrace, char),
+                "❔" => self.handle_optional_token(),
+                "⁉️" => Token::from_str(TokenType::Propagate, char),
+                "⏩" => Token::from_str(TokenType::Pipe, char),
+                "📥" => Token::from_str(TokenType::In, char),
                 _ if QUOTES.contains(char) => self.handle_string(),
+                "📜" | "📄" => self.handle_prefixed_string(),
                 _ if DIGITALS.contains(char) => self.handle_number(),
                 _ if NEWLINES.contains(char) => {
                     if let Some(token) = self.handle_new_line(&tokens) {
@@ -80,11 +123,41 @@ impl<'a> Lexer<'a> {
                 _ if is_identifier_char(char) => self.handle_identifier(),
                 _ => Token::from_str(TokenType::Illegal, char),
             };
+            Self::drop_stray_doc_comments(&mut tokens, Some(&token));
             tokens.push(token);
         }
+        Self::drop_stray_doc_comments(&mut tokens, None);
         tokens
     }
 
+    // doc comments not followed by a 📛 function are plain comments
+    fn drop_stray_doc_comments(tokens: &mut StatefulVector<Token>, next: Option<&Token>) {
+        let is_comment_run =
+            |token: &Token| [TokenType::DocComment, TokenType::Semicolon].contains(&token.token_type);
+        if next.is_some_and(|token| is_comment_run(token) || token.token_type == TokenType::Function)
+            || !tokens
+                .iter()
+                .rev()
+                .take_while(|token| is_comment_run(token))
+                .any(|token| token.token_type == TokenType::DocComment)
+        {
+            return;
+        }
+
+        let mut trailing = vec![];
+        while tokens.last().is_some_and(is_comment_run) {
+            trailing.push(tokens.pop().unwrap());
+        }
+        if let Some(semicolon) = trailing
+            .into_iter()
+            .rev()
+            .find(|token| token.token_type == TokenType::Semicolon)
+            && tokens.last().is_some_and(|token| token.token_type != TokenType::Start)
+        {
+            tokens.push(semicolon);
+        }
+    }
+
     fn handle_two_chars_token(
         &mut self,
         single_char_token_type: TokenType,
@@ -102,20 +175,94 @@ impl<'a> Lexer<'a> {
         Token::from(token_type, current_char)
     }
 
+    fn handle_optional_token(&mut self) -> Token {
+        for (expected_next_char, token_type) in [
+            ("❔", TokenType::NullCoalescing),
+            ("❇️", TokenType::OptionalMember),
+            ("👉", TokenType::OptionalLBracket),
+        ] {
+            if self.chars.is_next_eq(&expected_next_char) {
+                return self.handle_two_chars_token(TokenType::Illegal, expected_next_char, token_type);
+            }
+        }
+        Token::from_str(TokenType::Illegal, self.chars.current().unwrap())
+    }
+
     fn handle_string(&mut self) -> Token {
         let mut literal = String::from(*self.chars.current().unwrap());
-        while self
-            .chars
-            .is_next_match(|char| !QUOTES.contains(char) || *self.chars.current().unwrap() == "🪄")
-        {
+        self.scan_string_body(&mut literal);
+        Token::from(TokenType::String, literal)
+    }
+
+    fn handle_prefixed_string(&mut self) -> Token {
+        let mut literal = String::from(*self.chars.current().unwrap());
+        let mut raw_level = usize::from(literal == RAW_STRING_DELIMITER);
+        while self.chars.is_next_eq(&RAW_STRING_DELIMITER) {
             literal.push_str(self.chars.to_next().unwrap());
+            raw_level += 1;
         }
-        if self.chars.to_next().is_some() {
-            literal.push_str(self.chars.current().unwrap());
+        if !self.chars.is_next_match(|char| QUOTES.contains(char)) {
+            return Token::from(TokenType::Illegal, literal);
+        }
+        literal.push_str(self.chars.to_next().unwrap());
+
+        if raw_level == 0 {
+            self.scan_string_body(&mut literal);
+        } else {
+            self.scan_raw_string_body(&mut literal, raw_level);
         }
         Token::from(TokenType::String, literal)
     }
 
+    fn scan_raw_string_body(&mut self, literal: &mut String, raw_level: usize) {
+        while let Some(&char) = self.chars.to_next() {
+            literal.push_str(char);
+            if QUOTES.contains(&char)
+                && (1..=raw_level).all(|offset| self.chars.peek(offset) == Some(&RAW_STRING_DELIMITER))
+            {
+                for _ in 0..raw_level {
+                    literal.push_str(self.chars.to_next().unwrap());
+                }
+                return;
+            }
+        }
+    }
+
+    fn scan_string_body(&mut self, literal: &mut String) {
+        while let Some(&char) = self.chars.to_next() {
+            literal.push_str(char);
+            if QUOTES.contains(&char) {
+                return;
+            }
+            if char == "🪄"
+                && let Some(&escaped) = self.chars.to_next()
+            {
+                literal.push_str(escaped);
+                if escaped == "🌜" {
+                    self.scan_interpolation(literal);
+                }
+            }
+        }
+    }
+
+    fn scan_interpolation(&mut self, literal: &mut String) {
+        let mut depth = 1;
+        while let Some(&char) = self.chars.to_next() {
+            literal.push_str(char);
+            match char {
+                "🌜" => depth += 1,
+                "🌛" => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return;
+                    }
+                }
+                _ if QUOTES.contains(&char) => self.scan_string_body(literal),
+                _ => {}
+            }
+        }
+    }
+
     fn handle_number(&mut self) -> Token {
         let current_char = self.chars.current().unwrap().chars().next().unwrap();
         let mut literal = String::from(current_char);
@@ -159,11 +306,201 @@ impl<'a> Lexer<'a> {
         }
     }
 
+    // only doc comments produce a token
+    fn handle_comment(&mut self) -> Option<Token> {
+        if self.chars.is_next_eq(&"🫸") {
+            let (line, column) = self.location();
+            if !self.skip_block_comment() {
+                self.errors.push(format!(
+                    "Unterminated block comment #️⃣🫸 at line {line}, column {column}"
+                ));
+            }
+            None
+        } else if self.chars.is_next_eq(&"📖") {
+            Some(self.handle_doc_comment())
+        } else {
+            self.skip_comment();
+            None
+        }
+    }
+
+    // 1-based line and column of the current char, ignoring the space inserted by tokenize
+    fn location(&self) -> (usize, usize) {
+        let mut line = 1;
+        let mut column = 1;
+        for &char in self.chars.iter().take(self.chars.position()).skip(1) {
+            if NEWLINES.contains(&char) {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        (line, column)
+    }
+
     fn skip_comment(&mut self) {
         while self.chars.is_next_match(|&char| !NEWLINES.contains(&char)) {
             self.chars.to_next();
         }
     }
+
+    // block comments nest, returns false if the outermost one is never closed
+    fn skip_block_comment(&mut self) -> bool {
+        let mut depth = 0;
+        while let Some(&char) = self.chars.current() {
+            if char == "#️⃣" && self.chars.is_next_eq(&"🫸") {
+                depth += 1;
+                self.chars.to_next();
+            } else if char == "🫷" && self.chars.is_next_eq(&"#️⃣") {
+                depth -= 1;
+                self.chars.to_next();
+                if depth == 0 {
+                    return true;
+                }
+            }
+            if self.chars.to_next().is_none() {
+                return false;
+            }
+        }
+        false
+    }
+
+    fn handle_doc_comment(&mut self) -> Token {
+        self.chars.to_next();
+        if self.chars.is_next_match(|char| SPACES.contains(char)) {
+            self.chars.to_next();
+        }
+        let mut literal = String::new();
+        while self.chars.is_next_match(|&char| !NEWLINES.contains(&char)) {
+            literal.push_str(self.chars.to_next().unwrap());
+        }
+        Token::from(TokenType::DocComment, literal.trim_end().to_string())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum StringSegment {
+    Text(String),
+    Expression(String),
+}
+
+/// Splits the body of a string literal into plain text and the source of 🪄🌜 🌛 interpolations.
+/// Escapes other than interpolations are kept as is in the text segments.
+pub fn split_string_segments(body: &str) -> Vec<StringSegment> {
+    let mut lexer = Lexer::new(body);
+    lexer.chars.insert(0, "");
+
+    let mut segments = vec![];
+    let mut text = String::new();
+    while let Some(&char) = lexer.chars.to_next() {
+        if char == "🪄" && lexer.chars.is_next_eq(&"🌜") {
+            lexer.chars.to_next();
+            let mut source = String::new();
+            lexer.scan_interpolation(&mut source);
+            if !text.is_empty() {
+                segments.push(StringSegment::Text(std::mem::take(&mut text)));
+            }
+            let source = source.strip_suffix("🌛").unwrap_or(&source);
+            segments.push(StringSegment::Expression(source.to_string()));
+        } else {
+            text.push_str(char);
+            if char == "🪄"
+                && let Some(&escaped) = lexer.chars.to_next()
+            {
+                text.push_str(escaped);
+            }
+        }
+    }
+    if !text.is_empty() {
+        segments.push(StringSegment::Text(text));
+    }
+    segments
+}
+
+/// Decodes the 🪄 escape sequences in a text segment of a string literal:
+///
+/// | Escape          | Character                                           |
+/// |-----------------|-----------------------------------------------------|
+/// | `🪄↩️`          | line feed                                           |
+/// | `🪄🔙`          | carriage return                                     |
+/// | `🪄➡️`          | tab                                                 |
+/// | `🪄🪄`          | 🪄                                                  |
+/// | `🪄🗨️` / `🪄💬` | 🗨️ / 💬                                             |
+/// | `🪄🔣👉…👈`     | Unicode scalar value, 1-6 hex digits (`0`-`9️⃣`, `a`-`f`) |
+///
+/// `🪄🌜 🌛` interpolations are split out by [`split_string_segments`] beforehand.
+pub fn decode_escapes(text: &str) -> Result<String, String> {
+    let mut chars = text.graphemes(true);
+    let mut decoded = String::new();
+    while let Some(char) = chars.next() {
+        if char != "🪄" {
+            decoded.push_str(char);
+            continue;
+        }
+        match chars.next() {
+            Some("↩️") => decoded.push('\n'),
+            Some("🔙") => decoded.push('\r'),
+            Some("➡️") => decoded.push('\t'),
+            Some(escaped @ ("🪄" | "🗨️" | "💬")) => decoded.push_str(escaped),
+            Some("🔣") => decoded.push(decode_unicode_escape(&mut chars)?),
+            Some(escaped) => return Err(format!("Unknown escape sequence 🪄{escaped} in string literal")),
+            None => return Err(String::from("Expected a escaped character after 🪄 in string literal")),
+        }
+    }
+    Ok(decoded)
+}
+
+/// Strips the layout of a 📄 multi-line string literal: the line break right after the opening quote,
+/// the whitespace-only line before the closing quote and the indentation shared by all non-blank lines.
+pub fn strip_indentation(text: &str) -> String {
+    let is_blank = |line: &str| line.chars().all(|char| char == ' ' || char == '\t');
+    let text = text
+        .strip_prefix("\r\n")
+        .or_else(|| text.strip_prefix('\n'))
+        .unwrap_or(text);
+
+    let mut lines = text.lines().collect::<Vec<&str>>();
+    if lines.last().is_some_and(|line| is_blank(line)) {
+        lines.pop();
+    }
+    let indentation = lines
+        .iter()
+        .filter(|line| !is_blank(line))
+        .map(|line| line.len() - line.trim_start_matches([' ', '\t']).len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| if is_blank(line) { "" } else { &line[indentation..] })
+        .collect::<Vec<&str>>()
+        .join("\n")
+}
+
+fn decode_unicode_escape<'a>(chars: &mut impl Iterator<Item = &'a str>) -> Result<char, String> {
+    if chars.next() != Some("👉") {
+        return Err(String::from("Expected 👉 after 🪄🔣 in string literal"));
+    }
+    let mut hex = String::new();
+    loop {
+        match chars.next() {
+            Some("👈") => break,
+            Some(char) if DIGITALS.contains(&char) => hex.push(digital_emoji_to_char(char)),
+            Some(char) if char.len() == 1 && char.chars().all(|c| c.is_ascii_hexdigit()) => {
+                hex.push_str(char)
+            }
+            Some(char) => return Err(format!("Invalid hex digit {char} in 🪄🔣 escape")),
+            None => return Err(String::from("Expected 👈 at the end of 🪄🔣 escape")),
+        }
+    }
+    if hex.is_empty() || hex.len() > 6 {
+        return Err(format!("Expected 1-6 hex digits in 🪄🔣 escape, but got {}", hex.len()));
+    }
+    u32::from_str_radix(&hex, 16)
+        .ok()
+        .and_then(char::from_u32)
+        .ok_or_else(|| format!("Invalid Unicode scalar value in 🪄🔣 escape: {hex}"))
 }
 
 fn is_identifier_char(char: &str) -> bool {
@@ -290,4 +627,20 @@ mod lexer_test {
         let mut lexer = Lexer::new(&source);
         assert_eq!(lexer.tokenize().to_vec(), target);
     }
+
+    #[test]
+    fn test_decode_escapes() {
+        assert_eq!(
+            decode_escapes("🪄🗨️🪄🪄↩️🪄💬🪄↩️🪄🔙🪄➡️🪄🔣👉1️⃣F600👈🪄🔣👉e9👈"),
+            Ok(String::from("🗨️🪄↩️💬\n\r\t😀é"))
+        );
+        assert_eq!(
+            decode_escapes("🪄🈳"),
+            Err(String::from("Unknown escape sequence 🪄🈳 in string literal"))
+        );
+        assert_eq!(
+            decode_escapes("🪄🔣👉110000👈"),
+            Err(String::from("Invalid Unicode scalar value in 🪄🔣 escape: 110000"))
+        );
+    }
 }
@@ -4,3 +4,4 @@ pub mod parser;
 pub mod evaluator;
 pub mod repl;
 pub mod util;
+pub mod doc;
@@ -1,8 +1,17 @@
-use std::env;
+use std::{env, fs, process};
 
-use emolang::repl;
+use emolang::{doc, lexer::Lexer, parser::Parser, repl};
 
 fn main() {
+    // doc generator: emolang --doc <file>
+    let args = env::args().collect::<Vec<String>>();
+    if let [_, flag, path] = args.as_slice()
+        && flag == "--doc"
+    {
+        print_doc(path);
+        return;
+    }
+
     println!("Hello {}, Welcome to EMO programming language!", get_user_name());
     println!("Feel free to start coding ⌨️");
     repl::start();
@@ -16,3 +25,19 @@ fn get_user_name() -> String {
     }
     String::from("🧑🏻‍💻")
 }
+
+fn print_doc(path: &str) {
+    let source = fs::read_to_string(path).unwrap_or_else(|error| {
+        eprintln!("Cannot read {path}: {error}");
+        process::exit(1);
+    });
+    let mut lexer = Lexer::new(&source);
+    let mut parser = Parser::new(&mut lexer);
+    let program = parser.parse_program();
+
+    if !parser.errors().is_empty() {
+        eprintln!("Paser errors:\n\t{}", parser.errors().join("\n\t"));
+        process::exit(1);
+    }
+    print!("{}", doc::generate(&program));
+}
@@ -5,14 +5,29 @@ use std::{
 };
 
 use crate::{
-    lexer::Lexer,
-    types::{QUOTES, Token, TokenType, node::*},
+    lexer::{Lexer, StringSegment, decode_escapes, split_string_segments, strip_indentation},
+    types::{MULTILINE_STRING_PREFIX, QUOTES, RAW_STRING_DELIMITER, Token, TokenType, node::*},
     util::StatefulVector,
 };
 
 type PrefixParser = Rc<dyn Fn(&mut Parser) -> Result<Node, String>>;
 type InfixParser = Rc<dyn Fn(&mut Parser, Node) -> Result<Node, String>>;
 
+// operators user types can define methods for, named by the operator itself
+const OPERATOR_METHODS: [TokenType; 11] = [
+    TokenType::Plus,
+    TokenType::Minus,
+    TokenType::Multiply,
+    TokenType::Divide,
+    TokenType::Modulo,
+    TokenType::Equal,
+    TokenType::NotEqual,
+    TokenType::GreaterThan,
+    TokenType::GreaterThanOrEqual,
+    TokenType::LessThan,
+    TokenType::LessThanOrEqual,
+];
+
 pub struct Parser {
     tokens: StatefulVector<Token>,
     errors: Vec<String>,
@@ -26,7 +41,7 @@ impl Parser {
         let infix_exp_parsers = HashMap::new();
         let mut parser = Parser {
             tokens: lexer.tokenize(),
-            errors: vec![],
+            errors: lexer.errors().clone(),
             prefix_exp_parsers,
             infix_exp_parsers,
         };
@@ -51,6 +66,8 @@ impl Parser {
             .insert(TokenType::False, Rc::new(|p| p.parse_bool_literal()));
         self.prefix_exp_parsers
             .insert(TokenType::String, Rc::new(|p| p.parse_string_literal()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Null, Rc::new(|_| Ok(Node::NullLiteral)));
         self.prefix_exp_parsers
             .insert(TokenType::LBracket, Rc::new(|p| p.parse_list_literal()));
         self.prefix_exp_parsers
@@ -68,7 +85,35 @@ impl Parser {
         self.prefix_exp_parsers
             .insert(TokenType::Break, Rc::new(|p| p.parse_break_expression()));
         self.prefix_exp_parsers
-            .insert(TokenType::Function, Rc::new(|p| p.parse_function_literal()));
+            .insert(TokenType::Function, Rc::new(|p| p.parse_function_literal(None)));
+        self.prefix_exp_parsers
+            .insert(TokenType::DocComment, Rc::new(|p| p.parse_doc_comment()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Let, Rc::new(|p| p.parse_declare_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Const, Rc::new(|p| p.parse_declare_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Lambda, Rc::new(|p| p.parse_lambda_literal()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Match, Rc::new(|p| p.parse_match_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Try, Rc::new(|p| p.parse_try_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Throw, Rc::new(|p| p.parse_throw_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Delete, Rc::new(|p| p.parse_delete_expression()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Record, Rc::new(|p| p.parse_record_declaration()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Class, Rc::new(|p| p.parse_class_declaration()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Super, Rc::new(|_| Ok(Node::Super)));
+        self.prefix_exp_parsers
+            .insert(TokenType::Enum, Rc::new(|p| p.parse_enum_declaration()));
+        self.prefix_exp_parsers
+            .insert(TokenType::Wildcard, Rc::new(|_| Ok(Node::Wildcard)));
+        self.prefix_exp_parsers
+            .insert(TokenType::Spread, Rc::new(|p| p.parse_spread_element()));
 
         self.prefix_exp_parsers.insert(
             TokenType::LParenthesis,
@@ -107,6 +152,10 @@ impl Parser {
             TokenType::GreaterThanOrEqual,
             Rc::new(|p, left| p.parse_infix_expression(left)),
         );
+        self.infix_exp_parsers.insert(
+            TokenType::In,
+            Rc::new(|p, left| p.parse_infix_expression(left)),
+        );
         self.infix_exp_parsers.insert(
             TokenType::Plus,
             Rc::new(|p, left| p.parse_infix_expression(left)),
@@ -128,28 +177,76 @@ impl Parser {
             Rc::new(|p, left| p.parse_infix_expression(left)),
         );
 
+        self.infix_exp_parsers.insert(
+            TokenType::Pipe,
+            Rc::new(|p, left| p.parse_pipe_expression(left)),
+        );
+        self.infix_exp_parsers.insert(
+            TokenType::NullCoalescing,
+            Rc::new(|p, left| p.parse_infix_expression(left)),
+        );
+
         self.infix_exp_parsers.insert(
             TokenType::LBracket,
             Rc::new(|p, left| p.parse_index_expression(left))
         );
+        self.infix_exp_parsers.insert(
+            TokenType::OptionalLBracket,
+            Rc::new(|p, left| p.parse_index_expression(left))
+        );
         self.infix_exp_parsers.insert(
             TokenType::LParenthesis,
             Rc::new(|p, left| p.parse_call_expression(left)),
         );
+        self.infix_exp_parsers.insert(
+            TokenType::Propagate,
+            Rc::new(|_, left| {
+                Ok(Node::PropagateExpression {
+                    value: Box::new(left),
+                })
+            }),
+        );
         self.infix_exp_parsers.insert(
             TokenType::Member,
             Rc::new(|p, left| p.parse_member_expression(left)),
         );
+        self.infix_exp_parsers.insert(
+            TokenType::OptionalMember,
+            Rc::new(|p, left| p.parse_member_expression(left)),
+        );
         self.infix_exp_parsers.insert(
             TokenType::Assign,
             Rc::new(|p, left| p.parse_assign_expression(left)),
         );
+        self.infix_exp_parsers.insert(
+            TokenType::PlusAssign,
+            Rc::new(|p, left| p.parse_assign_expression(left)),
+        );
+        self.infix_exp_parsers.insert(
+            TokenType::MinusAssign,
+            Rc::new(|p, left| p.parse_assign_expression(left)),
+        );
+        self.infix_exp_parsers.insert(
+            TokenType::MultiplyAssign,
+            Rc::new(|p, left| p.parse_assign_expression(left)),
+        );
+        self.infix_exp_parsers.insert(
+            TokenType::DivideAssign,
+            Rc::new(|p, left| p.parse_assign_expression(left)),
+        );
+        self.infix_exp_parsers.insert(
+            TokenType::ModuloAssign,
+            Rc::new(|p, left| p.parse_assign_expression(left)),
+        );
     }
 
     pub fn parse_program(&mut self) -> Node {
         let mut statements = vec![];
 
-        while self.tokens.to_next().is_some() {
+        while let Some(token) = self.tokens.to_next() {
+            if token.token_type == TokenType::Semicolon {
+                continue;
+            }
             let statement = self.parse_statement();
             match statement {
                 Ok(statement) => statements.push(statement),
@@ -164,7 +261,9 @@ impl Parser {
         match self.tokens.current().unwrap().token_type {
             TokenType::Return => self.parse_return_statement(),
             TokenType::Semicolon => {
-                self.tokens.to_next();
+                if self.tokens.to_next().is_none() {
+                    return Err(String::from("Expected a statement, but arrived at the end"));
+                }
                 self.parse_statement()
             },
             _ => self.parse_expression_statement(),
@@ -285,16 +384,21 @@ impl Parser {
     }
 
     fn parse_string_literal(&self) -> Result<Node, String> {
-        let mut value = self
-            .tokens
-            .current()
-            .unwrap()
-            .literal
-            .clone()
-            .replace("🪄↩️", "\n")
-            .replace("🪄➡️", "\t")
-            .replace("🪄🗨️", "🗨️")
-            .replace("🪄💬", "💬");
+        let mut value = self.tokens.current().unwrap().literal.as_str();
+
+        let multiline = value.starts_with(MULTILINE_STRING_PREFIX);
+        value = value.trim_start_matches(MULTILINE_STRING_PREFIX);
+        let raw_delimiter = RAW_STRING_DELIMITER.repeat(
+            (value.len() - value.trim_start_matches(RAW_STRING_DELIMITER).len())
+                / RAW_STRING_DELIMITER.len(),
+        );
+        value = &value[raw_delimiter.len()..];
+        if !raw_delimiter.is_empty() {
+            value = value.strip_suffix(&raw_delimiter).ok_or_else(|| {
+                format!("Expected 🗨️{raw_delimiter} or 💬{raw_delimiter} at the end of a raw string literal")
+            })?;
+        }
+        let mut value = String::from(value);
 
         let mut has_prefix = false;
         let mut has_suffix = false;
@@ -316,7 +420,28 @@ impl Parser {
             return Err(String::from("Expected 🗨️ or 💬 at the end of a string literal"));
         }
 
-        Ok(Node::StringLiteral { value })
+        if multiline {
+            value = strip_indentation(&value);
+        }
+        if !raw_delimiter.is_empty() {
+            return Ok(Node::StringLiteral { value });
+        }
+
+        let mut segments = vec![];
+        for segment in split_string_segments(&value) {
+            segments.push(match segment {
+                StringSegment::Text(text) => Node::StringLiteral {
+                    value: decode_escapes(&text)?,
+                },
+                StringSegment::Expression(source) => parse_interpolation(&source)?,
+            });
+        }
+
+        match segments.as_slice() {
+            [] => Ok(Node::StringLiteral { value: String::new() }),
+            [Node::StringLiteral { .. }] => Ok(segments.remove(0)),
+            _ => Ok(Node::InterpolatedString { segments }),
+        }
     }
 
     fn parse_list_literal(&mut self) -> Result<Node, String> {
@@ -357,18 +482,31 @@ impl Parser {
         {
             let key = self.parse_expression(Precedence::Lowest)?;
 
-            if self
-                .tokens
-                .is_next_match(|token| token.token_type != TokenType::Describe)
+            if let Node::SpreadElement { .. } = key {
+                // rest of a map pattern
+                entries.push((key, Node::Wildcard));
+            } else if entries.is_empty()
+                && self
+                    .tokens
+                    .is_next_match(|token| token.token_type != TokenType::Describe)
             {
-                return Err(String::from("Expected a ➡️ between the key and value"));
-            }
-            self.tokens.to_next();
-            self.tokens.to_next();
+                // 🫸1️⃣🦶 2️⃣🫷 without ➡️ is a set
+                let elements = self.parse_remaining_elements(key, TokenType::RBrace)?;
+                return Ok(Node::SetLiteral { elements });
+            } else {
+                if self
+                    .tokens
+                    .is_next_match(|token| token.token_type != TokenType::Describe)
+                {
+                    return Err(String::from("Expected a ➡️ between the key and value"));
+                }
+                self.tokens.to_next();
+                self.tokens.to_next();
 
-            let value = self.parse_expression(Precedence::Lowest)?;
+                let value = self.parse_expression(Precedence::Lowest)?;
 
-            entries.push((key, value));
+                entries.push((key, value));
+            }
 
             if self
                 .tokens
@@ -388,6 +526,27 @@ impl Parser {
         Ok(Node::MapLiteral { entries })
     }
 
+    // the comma separated elements following the first one of a set or tuple literal
+    fn parse_remaining_elements(&mut self, first: Node, end: TokenType) -> Result<Vec<Node>, String> {
+        let mut elements = vec![first];
+        while self
+            .tokens
+            .is_next_match(|token| token.token_type == TokenType::Comma)
+        {
+            self.tokens.to_next();
+            if self.tokens.is_next_match(|token| token.token_type == end) {
+                break;
+            }
+            self.tokens.to_next();
+            elements.push(self.parse_expression(Precedence::Lowest)?);
+        }
+
+        if let Some(token) = self.tokens.to_next().filter(|token| token.token_type != end) {
+            return Err(format!("Expected a comma, but got a {}", token.literal));
+        }
+        Ok(elements)
+    }
+
     fn parse_prefix_expression(&mut self) -> Result<Node, String> {
         let operator = self
             .tokens
@@ -423,21 +582,81 @@ impl Parser {
         })
     }
 
+    fn parse_pipe_expression(&mut self, argument: Node) -> Result<Node, String> {
+        self.tokens.to_next();
+        let function = self.parse_expression(Precedence::Pipe)?;
+        pipe_into(argument, function)
+    }
+
     fn parse_assign_expression(&mut self, identifier: Node) -> Result<Node, String> {
+        let operator = self
+            .tokens
+            .current()
+            .unwrap()
+            .literal
+            .clone();
         self.tokens.to_next();
         let value = Box::new(self.parse_expression(Precedence::Lowest)?);
 
         Ok(Node::AssignExpression {
             identifier: Box::new(identifier),
+            operator,
             value
         })
     }
 
+    fn parse_declare_expression(&mut self) -> Result<Node, String> {
+        let token = self.tokens.current().unwrap().clone();
+        self.tokens.to_next();
+
+        match self.parse_expression(Precedence::Lowest)? {
+            Node::AssignExpression {
+                identifier,
+                operator,
+                value,
+            } if operator == "⬅️"
+                && matches!(
+                    *identifier,
+                    Node::Identifier { .. }
+                        | Node::ListLiteral { .. }
+                        | Node::TupleLiteral { .. }
+                        | Node::MapLiteral { .. }
+                ) =>
+            {
+                Ok(Node::DeclareExpression {
+                    mutable: token.token_type == TokenType::Let,
+                    identifier,
+                    value,
+                })
+            }
+            node => Err(format!(
+                "Expected {} identifier ⬅️ value, but got {} {}",
+                token.literal,
+                token.literal,
+                node.string()
+            )),
+        }
+    }
+
     fn parse_group_expression(&mut self) -> Result<Node, String> {
+        if self
+            .tokens
+            .is_next_match(|token| token.token_type == TokenType::RParenthesis)
+        {
+            self.tokens.to_next();
+            return Ok(Node::TupleLiteral { elements: vec![] });
+        }
         self.tokens.to_next();
         let exp = self.parse_expression(Precedence::Lowest)?;
 
         if self
+            .tokens
+            .is_next_match(|token| token.token_type == TokenType::Comma)
+        {
+            // 🌜1️⃣🦶 2️⃣🌛 and 🌜1️⃣🦶🌛 are tuples
+            let elements = self.parse_remaining_elements(exp, TokenType::RParenthesis)?;
+            Ok(Node::TupleLiteral { elements })
+        } else if self
             .tokens
             .is_next_match(|token| token.token_type != TokenType::RParenthesis)
         {
@@ -510,6 +729,87 @@ impl Parser {
         })
     }
 
+    fn parse_try_expression(&mut self) -> Result<Node, String> {
+        if self
+            .tokens
+            .is_next_match(|token| token.token_type != TokenType::LBrace)
+        {
+            return Err(String::from("Expected a block statement after 🤞"));
+        }
+        self.tokens.to_next();
+        let body = Box::new(self.parse_block_statement()?);
+
+        let mut error = None;
+        let mut handler = None;
+        if self
+            .tokens
+            .is_next_match(|token| token.token_type == TokenType::Catch)
+        {
+            self.tokens.to_next();
+            if self
+                .tokens
+                .is_next_match(|token| token.token_type == TokenType::Identifier)
+            {
+                self.tokens.to_next();
+                error = Some(Box::new(self.parse_identifier()?));
+            }
+            if self
+                .tokens
+                .is_next_match(|token| token.token_type != TokenType::LBrace)
+            {
+                return Err(String::from("Expected a block statement after 🥅"));
+            }
+            self.tokens.to_next();
+            handler = Some(Box::new(self.parse_block_statement()?));
+        }
+
+        let finalizer = if self
+            .tokens
+            .is_next_match(|token| token.token_type == TokenType::Finally)
+        {
+            self.tokens.to_next();
+            if self
+                .tokens
+                .is_next_match(|token| token.token_type != TokenType::LBrace)
+            {
+                return Err(String::from("Expected a block statement after 🏁"));
+            }
+            self.tokens.to_next();
+            Some(Box::new(self.parse_block_statement()?))
+        } else {
+            None
+        };
+
+        if handler.is_none() && finalizer.is_none() {
+            return Err(String::from("Expected 🥅 or 🏁 after the 🤞 block"));
+        }
+        Ok(Node::TryExpression {
+            body,
+            error,
+            handler,
+            finalizer,
+        })
+    }
+
+    fn parse_throw_expression(&mut self) -> Result<Node, String> {
+        self.tokens.to_next();
+        let value = Box::new(self.parse_expression(Precedence::Lowest)?);
+        Ok(Node::ThrowExpression { value })
+    }
+
+    fn parse_delete_expression(&mut self) -> Result<Node, String> {
+        self.tokens.to_next();
+        match self.parse_expression(Precedence::Prefix)? {
+            target @ Node::IndexExpression { .. } => Ok(Node::DeleteExpression {
+                target: Box::new(target),
+            }),
+            target => Err(format!(
+                "Expected an index expression after 🗑️, but got {}",
+                target.string()
+            )),
+        }
+    }
+
     fn parse_break_expression(&mut self) -> Result<Node, String> {
         let token = self.tokens.to_next();
 
@@ -522,11 +822,81 @@ impl Parser {
         Ok(Node::BreakExpression { value })
     }
 
-    fn parse_function_literal(&mut self) -> Result<Node, String> {
+    fn parse_match_expression(&mut self) -> Result<Node, String> {
+        self.tokens.to_next();
+        let subject = Box::new(self.parse_expression(Precedence::Lowest)?);
+
+        if self
+            .tokens
+            .is_next_match(|token| token.token_type != TokenType::LBrace)
+        {
+            return Err(String::from("Expected a block of arms after match-subject"));
+        }
+        self.tokens.to_next();
+
+        let mut arms = vec![];
+        while let Some(token) = self
+            .tokens
+            .to_next()
+            .filter(|token| token.token_type != TokenType::RBrace)
+        {
+            if [TokenType::Semicolon, TokenType::Comma].contains(&token.token_type) {
+                continue;
+            }
+
+            let pattern = self.parse_expression(Precedence::Lowest)?;
+
+            let guard = if self
+                .tokens
+                .is_next_match(|token| token.token_type == TokenType::If)
+            {
+                self.tokens.to_next();
+                self.tokens.to_next();
+                Some(self.parse_expression(Precedence::Lowest)?)
+            } else {
+                None
+            };
+
+            if self
+                .tokens
+                .is_next_match(|token| token.token_type != TokenType::Describe)
+            {
+                return Err(String::from("Expected a ➡️ between the pattern and arm body"));
+            }
+            self.tokens.to_next();
+            self.tokens.to_next();
+
+            let body = if self
+                .tokens
+                .current()
+                .is_some_and(|token| token.token_type == TokenType::LBrace)
+            {
+                self.parse_block_statement()?
+            } else {
+                self.parse_expression(Precedence::Lowest)?
+            };
+
+            arms.push((pattern, guard, body));
+        }
+
+        Ok(Node::MatchExpression { subject, arms })
+    }
+
+    fn parse_spread_element(&mut self) -> Result<Node, String> {
+        if self.tokens.to_next().is_some() {
+            let value = Box::new(self.parse_expression(Precedence::Prefix)?);
+            Ok(Node::SpreadElement { value })
+        } else {
+            Err(String::from("Expected a expression after 🧺"))
+        }
+    }
+
+    fn parse_function_literal(&mut self, doc: Option<String>) -> Result<Node, String> {
         let mut name = None;
-        let mut parameters = vec![];
 
-        if self.tokens.is_next_match(|token| token.token_type == TokenType::Identifier) {
+        if self.tokens.is_next_match(|token| {
+            token.token_type == TokenType::Identifier || OPERATOR_METHODS.contains(&token.token_type)
+        }) {
             self.tokens.to_next();
             name = Some(Box::new(self.parse_identifier()?));
         }
@@ -536,43 +906,244 @@ impl Parser {
         }
 
         self.tokens.to_next();
-        
-        while let Some(token) = self.tokens.to_next().filter(|token| token.token_type != TokenType::RParenthesis) {
-            if token.token_type != TokenType::Identifier {
-                return Err(format!("Expected a identifier, but got a {}", token.literal));
-            }
-            parameters.push(self.parse_identifier()?);
+        let parameters = self.parse_function_parameters(TokenType::RParenthesis)?;
 
-            if self.tokens.is_next_match(|token| token.token_type == TokenType::RParenthesis) {
-                continue;
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::LBrace) {
+            return Err("Expected a left brace".to_string());
+        }
+
+        self.tokens.to_next();
+        let body = Box::new(self.parse_block_statement()?);
+
+        Ok(Node::FunctionLiteral {
+            name,
+            doc,
+            parameters,
+            body,
+        })
+    }
+
+    fn parse_doc_comment(&mut self) -> Result<Node, String> {
+        let mut lines = vec![self.tokens.current().unwrap().literal.clone()];
+        while self.tokens.is_next_match(|token| {
+            [TokenType::DocComment, TokenType::Semicolon].contains(&token.token_type)
+        }) {
+            let token = self.tokens.to_next().unwrap();
+            if token.token_type == TokenType::DocComment {
+                lines.push(token.literal.clone());
             }
+        }
 
-            if let Some(token) = self.tokens.to_next().filter(|token| token.token_type != TokenType::Comma) {
-                return Err(format!("Expected a comma, but got a {}", token.literal));
+        if !self.tokens.is_next_match(|token| token.token_type == TokenType::Function) {
+            return Err(String::from("Expected a 📛 function after a #️⃣📖 doc comment"));
+        }
+        self.tokens.to_next();
+        self.parse_function_literal(Some(lines.join("\n")))
+    }
+
+    fn parse_record_declaration(&mut self) -> Result<Node, String> {
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::Identifier) {
+            return Err(String::from("Expected a record name after 🧱"));
+        }
+        self.tokens.to_next();
+        let name = Box::new(self.parse_identifier()?);
+
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::LBrace) {
+            return Err(String::from("Expected a left brace"));
+        }
+        self.tokens.to_next();
+        let fields = self.parse_function_parameters(TokenType::RBrace)?;
+        if let Some(field) = fields
+            .iter()
+            .find(|field| matches!(field, Node::SpreadElement { .. }))
+        {
+            return Err(format!("Unexpected {} field in record {}", field.string(), name.string()));
+        }
+
+        Ok(Node::RecordDeclaration { name, fields })
+    }
+
+    fn parse_class_declaration(&mut self) -> Result<Node, String> {
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::Identifier) {
+            return Err(String::from("Expected a class name after 🏛️"));
+        }
+        self.tokens.to_next();
+        let name = Box::new(self.parse_identifier()?);
+
+        let parent = if self.tokens.is_next_match(|token| token.token_type == TokenType::Inherits) {
+            self.tokens.to_next();
+            if self.tokens.is_next_match(|token| token.token_type != TokenType::Identifier) {
+                return Err(String::from("Expected a parent class name after 🧬"));
             }
+            self.tokens.to_next();
+            Some(Box::new(self.parse_identifier()?))
+        } else {
+            None
+        };
+
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::LBrace) {
+            return Err(String::from("Expected a left brace"));
+        }
+        self.tokens.to_next();
+        let Node::BlockStatement { statements } = self.parse_block_statement()? else {
+            unreachable!()
+        };
+        let methods = statements
+            .into_iter()
+            .map(|statement| match statement {
+                Node::ExpressionStatement { expression }
+                    if matches!(*expression, Node::FunctionLiteral { name: Some(_), .. }) =>
+                {
+                    Ok(*expression)
+                }
+                _ => Err(format!(
+                    "Expected a named 📛 method in class {}, but got {}",
+                    name.string(),
+                    statement.string()
+                )),
+            })
+            .collect::<Result<Vec<Node>, String>>()?;
+
+        Ok(Node::ClassDeclaration {
+            name,
+            parent,
+            methods,
+        })
+    }
+
+    fn parse_enum_declaration(&mut self) -> Result<Node, String> {
+        if self.tokens.is_next_match(|token| token.token_type != TokenType::Identifier) {
+            return Err(String::from("Expected an enum name after 🧩"));
         }
+        self.tokens.to_next();
+        let name = Box::new(self.parse_identifier()?);
 
         if self.tokens.is_next_match(|token| token.token_type != TokenType::LBrace) {
-            return Err("Expected a left brace".to_string());
+            return Err(String::from("Expected a left brace"));
+        }
+        self.tokens.to_next();
+
+        let mut variants = vec![];
+        loop {
+            let Some(token) = self.tokens.to_next().cloned() else {
+                return Err(String::from("Expected a right brace"));
+            };
+            match token.token_type {
+                TokenType::RBrace => break,
+                TokenType::Semicolon | TokenType::Comma => continue,
+                TokenType::Identifier => {
+                    let variant = self.parse_identifier()?;
+                    let fields = if self
+                        .tokens
+                        .is_next_match(|token| token.token_type == TokenType::LParenthesis)
+                    {
+                        self.tokens.to_next();
+                        let fields = self.parse_function_parameters(TokenType::RParenthesis)?;
+                        if let Some(field) = fields
+                            .iter()
+                            .find(|field| matches!(field, Node::SpreadElement { .. }))
+                        {
+                            return Err(format!(
+                                "Unexpected {} field in variant {}",
+                                field.string(),
+                                variant.string()
+                            ));
+                        }
+                        Some(fields)
+                    } else {
+                        None
+                    };
+                    variants.push((variant, fields));
+                }
+                _ => return Err(format!("Expected a variant name, but got {}", token.literal)),
+            }
+        }
+
+        Ok(Node::EnumDeclaration { name, variants })
+    }
+
+    fn parse_lambda_literal(&mut self) -> Result<Node, String> {
+        let parameters = self.parse_function_parameters(TokenType::Describe)?;
+
+        if self
+            .tokens
+            .current()
+            .is_none_or(|token| token.token_type != TokenType::Describe)
+        {
+            return Err(String::from("Expected a ➡️ between the lambda parameters and body"));
         }
 
         self.tokens.to_next();
-        let body = Box::new(self.parse_block_statement()?);
+        let value = Box::new(self.parse_expression(Precedence::Lowest)?);
 
         Ok(Node::FunctionLiteral {
-            name,
+            name: None,
+            doc: None,
             parameters,
-            body,
+            body: Box::new(Node::BlockStatement {
+                statements: vec![Node::ReturnStatement { value }],
+            }),
         })
     }
 
+    fn parse_function_parameters(&mut self, end: TokenType) -> Result<Vec<Node>, String> {
+        let mut parameters: Vec<Node> = vec![];
+
+        while let Some(token) = self.tokens.to_next().filter(|token| token.token_type != end) {
+            let after_rest = matches!(parameters.last(), Some(Node::SpreadElement { .. }));
+            if after_rest && token.token_type != TokenType::Spread {
+                return Err(format!("Expected 🧺 parameter at the end, but got a {}", token.literal));
+            }
+
+            let parameter = match token.token_type {
+                TokenType::Identifier => {
+                    let identifier = self.parse_identifier()?;
+                    if self.tokens.is_next_match(|token| token.token_type == TokenType::Assign) {
+                        // default value
+                        self.tokens.to_next();
+                        self.parse_assign_expression(identifier)?
+                    } else {
+                        identifier
+                    }
+                }
+                TokenType::Spread => match self.parse_spread_element()? {
+                    Node::SpreadElement { value } if !matches!(*value, Node::Identifier { .. }) => {
+                        return Err(format!("Expected a identifier, but got a {}", value.string()));
+                    }
+                    rest => rest,
+                },
+                _ => return Err(format!("Expected a identifier, but got a {}", token.literal)),
+            };
+            if after_rest {
+                return Err(format!("Expected a single 🧺 parameter, but got a second one {}", parameter.string()));
+            }
+            let name = parameter_name(&parameter);
+            if parameters.iter().any(|other| parameter_name(other) == name) {
+                return Err(format!("Duplicate parameter {name}"));
+            }
+            parameters.push(parameter);
+
+            if self.tokens.is_next_match(|token| token.token_type == end) {
+                continue;
+            }
+
+            if let Some(token) = self.tokens.to_next().filter(|token| token.token_type != TokenType::Comma) {
+                return Err(format!("Expected a comma, but got a {}", token.literal));
+            }
+        }
+
+        Ok(parameters)
+    }
+
     fn parse_index_expression(&mut self, list: Node) -> Result<Node, String> {
+        let optional = self.tokens.current().unwrap().token_type == TokenType::OptionalLBracket;
         self.tokens.to_next();
         let index = self.parse_expression(Precedence::Lowest)?;
         if let Some(tok) = self.tokens.to_next() && tok.token_type == TokenType::RBracket {
             Ok(Node::IndexExpression {
                 collection: Box::new(list),
                 index: Box::new(index),
+                optional,
             })
         } else {
             Err("Expected a right bracket".to_string())
@@ -582,8 +1153,23 @@ impl Parser {
     fn parse_call_expression(&mut self, function: Node) -> Result<Node, String> {
         let mut arguments = vec![];
 
-        while self.tokens.to_next().filter(|token| token.token_type != TokenType::RParenthesis).is_some() {
-            arguments.push(self.parse_expression(Precedence::Lowest)?);
+        while let Some(token) = self.tokens.to_next().filter(|token| token.token_type != TokenType::RParenthesis) {
+            let is_identifier = token.token_type == TokenType::Identifier;
+            let argument = if is_identifier
+                && self.tokens.is_next_match(|token| token.token_type == TokenType::Describe)
+            {
+                let name = Box::new(self.parse_identifier()?);
+                self.tokens.to_next();
+                self.tokens.to_next();
+                let value = Box::new(self.parse_expression(Precedence::Lowest)?);
+                Node::NamedArgument { name, value }
+            } else if let Some(Node::NamedArgument { .. }) = arguments.last() {
+                let token = self.tokens.current().unwrap();
+                return Err(format!("Expected a named argument, but got a {}", token.literal));
+            } else {
+                self.parse_expression(Precedence::Lowest)?
+            };
+            arguments.push(argument);
 
             if self.tokens.is_next_match(|token| token.token_type == TokenType::RParenthesis) {
                 continue;
@@ -601,6 +1187,7 @@ impl Parser {
     }
 
     fn parse_member_expression(&mut self, instance: Node) -> Result<Node, String> {
+        let optional = self.tokens.current().unwrap().token_type == TokenType::OptionalMember;
         let member = if let Some(token) = self.tokens.to_next() {
             if token.token_type == TokenType::Identifier {
                 let identifier = Node::Identifier {
@@ -610,12 +1197,8 @@ impl Parser {
                     // method
                     self.tokens.to_next();
                     self.parse_call_expression(identifier)?
-                } else if self.tokens.is_next_match(|tok| tok.token_type == TokenType::LBracket) {
-                    // index expression
-                    self.tokens.to_next();
-                    self.parse_index_expression(identifier)?
                 } else {
-                    // field
+                    // field, an index like 🅰️❇️🅻👉0️⃣👈 applies to the field and is evaluated in the caller scope
                     identifier
                 }
             } else {
@@ -625,7 +1208,78 @@ impl Parser {
             return Err(String::from("Expected a identifier, but arrived at the end"))
         };
 
-        Ok(Node::MemberExpression { instance: Box::new(instance), member: Box::new(member) })
+        Ok(Node::MemberExpression { instance: Box::new(instance), member: Box::new(member), optional })
+    }
+}
+
+fn parse_interpolation(source: &str) -> Result<Node, String> {
+    let mut lexer = Lexer::new(source);
+    let mut parser = Parser::new(&mut lexer);
+    let program = parser.parse_program();
+    if let Some(error) = parser.errors().first() {
+        return Err(format!("Invalid interpolation 🪄🌜{source}🌛: {error}"));
+    }
+    match program {
+        Node::Program { mut statements } if statements.len() == 1 => match statements.remove(0) {
+            Node::ExpressionStatement { expression } => Ok(*expression),
+            _ => Err(format!("Expected a expression in interpolation 🪄🌜{source}🌛")),
+        },
+        _ => Err(format!("Expected a expression in interpolation 🪄🌜{source}🌛")),
+    }
+}
+
+// the name bound by a parameter: 🅰️, 🅰️ ⬅️ default or 🧺🅰️
+fn parameter_name(parameter: &Node) -> String {
+    match parameter {
+        Node::AssignExpression { identifier, .. } => identifier.string(),
+        Node::SpreadElement { value } => value.string(),
+        parameter => parameter.string(),
+    }
+}
+
+// `x ⏩ f🌜a🌛` => `f🌜x🦶 a🌛`, `x ⏩ f` => `f🌜x🌛`, `x ⏩ o❇️m` => `o❇️m🌜x🌛`
+fn pipe_into(argument: Node, function: Node) -> Result<Node, String> {
+    match function {
+        Node::CallExpression {
+            function,
+            mut arguments,
+        } => {
+            arguments.insert(0, argument);
+            Ok(Node::CallExpression {
+                function,
+                arguments,
+            })
+        }
+        Node::MemberExpression {
+            instance,
+            member,
+            optional,
+        } if matches!(*member, Node::CallExpression { .. } | Node::Identifier { .. }) => {
+            Ok(Node::MemberExpression {
+                instance,
+                member: Box::new(pipe_into(argument, *member)?),
+                optional,
+            })
+        }
+        Node::IntegerLiteral { .. }
+        | Node::FloatLiteral { .. }
+        | Node::BooleanLiteral { .. }
+        | Node::StringLiteral { .. }
+        | Node::InterpolatedString { .. }
+        | Node::NullLiteral
+        | Node::ListLiteral { .. }
+        | Node::MapLiteral { .. }
+        | Node::SetLiteral { .. }
+        | Node::TupleLiteral { .. }
+        | Node::PrefixExpression { .. }
+        | Node::InfixExpression { .. } => Err(format!(
+            "Expected a function or method after ⏩, but got {}",
+            function.string()
+        )),
+        function => Ok(Node::CallExpression {
+            function: Box::new(function),
+            arguments: vec![argument],
+        }),
     }
 }
 
@@ -679,4 +1333,145 @@ mod parser_test {
         assert_eq!(parser.errors.len(), target_errors.len());
         assert_eq!(parser.errors, target_errors);
     }
+
+    #[test]
+    fn test_string_literals() {
+        let source = "
+        📜🗨️🪄↩️💬📜
+        📜📜🗨️🗨️💬📜💬📜📜
+        🗨️🪄🪄 🪄🌜1️⃣🌛💬
+        📄🗨️
+            🅰️
+              🅱️
+        💬
+        ";
+        let target_statements = [
+            "📜🗨️🪄↩️💬📜 ↙️",
+            "📜📜🗨️🗨️💬📜💬📜📜 ↙️",
+            "🗨️🪄🪄 🪄🌜1️⃣🌛💬 ↙️",
+            "🗨️🅰️\n  🅱️💬 ↙️",
+        ];
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert!(parser.errors.is_empty(), "{:?}", parser.errors);
+        assert_eq!(program.string(), target_statements.join(""));
+    }
+
+    #[test]
+    fn test_set_literal() {
+        let source = "
+        🫸 1️⃣🦶 2️⃣ 📥 🅰️ 🫷
+        🫸 🅰️ 🫷
+        🫸 🫸1️⃣🫷🦶 🧺🅱️ 🫷
+        🫸 1️⃣ ➡️ 2️⃣🦶 3️⃣ 🫷
+        ";
+        let target_statements = [
+            "🫸1️⃣🦶 🌜2️⃣ 📥 🅰️🌛🫷 ↙️",
+            "🫸🅰️🫷 ↙️",
+            "🫸🫸1️⃣🫷🦶 🧺🅱️🫷 ↙️",
+        ];
+        let target_errors = vec![
+            "Expected a ➡️ between the key and value",
+            "Expected a expression, but got a 🫷",
+        ];
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert_eq!(program.string(), target_statements.join(""));
+        assert_eq!(parser.errors, target_errors);
+    }
+
+    #[test]
+    fn test_tuple_literal() {
+        let source = "
+        🌜🌜1️⃣🦶🌛🦶 🌜🌛🦶 🌜2️⃣🌛🌛
+        🌜🅰️🦶 🅱️🌛 ⬅️ 🌜🅱️🦶 🅰️🌛
+        🌜🌜1️⃣🦶 2️⃣🌛🌛👉0️⃣👈
+        🌜1️⃣🦶 2️⃣ 🫷
+        ";
+        let target_statements = [
+            "🌜🌜1️⃣🦶🌛🦶 🌜🌛🦶 2️⃣🌛 ↙️",
+            "🌜🅰️🦶 🅱️🌛 ⬅️ 🌜🅱️🦶 🅰️🌛 ↙️",
+            "🌜1️⃣🦶 2️⃣🌛👉0️⃣👈 ↙️",
+        ];
+        let target_errors = vec!["Expected a comma, but got a 🫷"];
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert_eq!(program.string(), target_statements.join(""));
+        assert_eq!(parser.errors, target_errors);
+    }
+
+    #[test]
+    fn test_pipe_expression() {
+        let source = "
+        🅰️ ⏩ 🅵 ⏩ 🅶🌜1️⃣🌛
+        🅰️ ⏩ 🅻❇️📏 ⏩ 🅻❔❇️🔍🌜1️⃣🌛
+        🅰️ ⏩ 1️⃣ ➕ 🅱️
+        ";
+        let target_statements = [
+            "🅶🌜🅵🌜🅰️🌛🦶 1️⃣🌛 ↙️",
+            "🅻❔❇️🔍🌜🅻❇️📏🌜🅰️🌛🦶 1️⃣🌛 ↙️",
+        ];
+        let target_errors = vec!["Expected a function or method after ⏩, but got 🌜1️⃣ ➕ 🅱️🌛"];
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert_eq!(program.string(), target_statements.join(""));
+        assert_eq!(parser.errors, target_errors);
+    }
+
+    #[test]
+    fn test_function_parameters() {
+        let parse_errors = |source: &str| {
+            let mut lexer = Lexer::new(source);
+            let mut parser = Parser::new(&mut lexer);
+            let program = parser.parse_program();
+            (program.string(), parser.errors)
+        };
+
+        let (program, errors) = parse_errors("📛 🅵 🌜🅰️🦶 🅱️ ⬅️ 1️⃣🦶 🧺🅲🌛 🫸 🅰️ 🫷");
+        assert_eq!(program, "📛 🅵 🌜🅰️🦶 🅱️ ⬅️ 1️⃣🦶 🧺🅲🌛 🫸 🅰️ ↙️ 🫷 ↙️");
+        assert!(errors.is_empty(), "{errors:?}");
+
+        assert_eq!(parse_errors("📛 🅵 🌜🅰️🦶 🅰️🌛 🫸 🅰️ 🫷").1[0], "Duplicate parameter 🅰️");
+        assert_eq!(parse_errors("📛 🅵 🌜🅰️🦶 🧺🅰️🌛 🫸 🅰️ 🫷").1[0], "Duplicate parameter 🅰️");
+        assert_eq!(
+            parse_errors("📛 🅵 🌜🧺🅰️🦶 🧺🅱️🌛 🫸 🅰️ 🫷").1[0],
+            "Expected a single 🧺 parameter, but got a second one 🧺🅱️"
+        );
+    }
+
+    #[test]
+    fn test_comments() {
+        let source = "
+        #️⃣📖 not followed by a function
+        🅰️ ⬅️ 1️⃣
+        📛 🅵 🌜🌛 🫸
+          #️⃣📖 first line of the body
+          🅰️ #️⃣📖 trailing
+        🫷
+        #️⃣🫸 unterminated
+        #️⃣🫸 nested 🫷#️⃣
+        🅰️
+        ";
+        let target_statements = ["🅰️ ⬅️ 1️⃣ ↙️", "📛 🅵 🌜🌛 🫸 🅰️ ↙️ 🫷 ↙️"];
+        let target_errors = vec!["Unterminated block comment #️⃣🫸 at line 8, column 9"];
+
+        let mut lexer = Lexer::new(source);
+        let mut parser = Parser::new(&mut lexer);
+        let program = parser.parse_program();
+
+        assert_eq!(program.string(), target_statements.join(""));
+        assert_eq!(parser.errors, target_errors);
+    }
 }
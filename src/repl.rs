@@ -1,9 +1,10 @@
 use std::io::{self, Write};
 
-use crate::{evaluator::eval, lexer::Lexer, parser::Parser, types::Environment};
+use crate::{doc, evaluator::eval, lexer::Lexer, parser::Parser, types::Environment};
 
 pub fn start() {
     let mut env = Environment::new_default();
+    let mut doc_comments = String::new();
     loop {
         print!(">> ");
         io::stdout()
@@ -13,6 +14,28 @@ pub fn start() {
         io::stdin()
             .read_line(&mut line)
             .expect("Cannot read from console input");
+
+        // help command: 📖 name
+        if let Some(name) = line.trim().strip_prefix("📖") {
+            let name = name.trim().to_string();
+            match env.get(&name) {
+                Some(object) => println!(
+                    "{}",
+                    doc::describe(&object.borrow())
+                        .unwrap_or_else(|| format!("No documentation for {name}"))
+                ),
+                None => println!("Unknown identifier {name}"),
+            }
+            continue;
+        }
+
+        // doc comments are kept until the function they document is entered
+        if line.trim().starts_with("#️⃣📖") {
+            doc_comments.push_str(&line);
+            continue;
+        }
+        let line = std::mem::take(&mut doc_comments) + &line;
+
         let mut lexer = Lexer::new(&line);
         let mut parser = Parser::new(&mut lexer);
         let program = parser.parse_program();
@@ -1,27 +1,36 @@
 use crate::{
-    types::{Token, TokenType},
-    util::emoji_convert::{boolean_to_emoji, float_to_emoji, integer_to_emoji},
+    types::{QUOTES, RAW_STRING_DELIMITER, Token, TokenType},
+    util::emoji_convert::{boolean_to_emoji, float_to_emoji, integer_to_emoji, null_to_emoji},
 };
 
 #[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub enum Precedence {
     Lowest,
-    Assign,      // ⬅️
+    Assign,      // ⬅️/➕⬅️/➖⬅️/✖️⬅️/➗⬅️/〰️⬅️
+    Pipe,        // ⏩
+    Coalesce,    // ❔❔
     Or,          // 🔀
     And,         // 🔁
     Equals,      // 🟰/❗🟰
-    LessGreater, // ▶️/▶️🟰/◀️/◀️🟰
+    LessGreater, // ▶️/▶️🟰/◀️/◀️🟰/📥
     Sum,         // ➕/➖
     Product,     // ✖️/➗/〰️
     Prefix,      // ➖x/⏸️x
     Call,        // fn🌜🌛
-    Index,       // list/map👉 👈 instance❇️
+    Index,       // list/map👉 👈 instance❇️ list/map❔👉 👈 instance❔❇️
 }
 
 impl Precedence {
     pub fn get_operator_precedence(token: &Token) -> Precedence {
         match token.token_type {
             TokenType::Assign => Precedence::Assign,
+            TokenType::PlusAssign => Precedence::Assign,
+            TokenType::MinusAssign => Precedence::Assign,
+            TokenType::MultiplyAssign => Precedence::Assign,
+            TokenType::DivideAssign => Precedence::Assign,
+            TokenType::ModuloAssign => Precedence::Assign,
+            TokenType::Pipe => Precedence::Pipe,
+            TokenType::NullCoalescing => Precedence::Coalesce,
             TokenType::Or => Precedence::Or,
             TokenType::And => Precedence::And,
             TokenType::Equal => Precedence::Equals,
@@ -30,6 +39,7 @@ impl Precedence {
             TokenType::LessThanOrEqual => Precedence::LessGreater,
             TokenType::GreaterThan => Precedence::LessGreater,
             TokenType::GreaterThanOrEqual => Precedence::LessGreater,
+            TokenType::In => Precedence::LessGreater,
             TokenType::Plus => Precedence::Sum,
             TokenType::Minus => Precedence::Sum,
             TokenType::Multiply => Precedence::Product,
@@ -38,6 +48,9 @@ impl Precedence {
             TokenType::LParenthesis => Precedence::Call,
             TokenType::LBracket => Precedence::Index,
             TokenType::Member => Precedence::Index,
+            TokenType::OptionalLBracket => Precedence::Index,
+            TokenType::OptionalMember => Precedence::Index,
+            TokenType::Propagate => Precedence::Index,
             _ => Precedence::Lowest,
         }
     }
@@ -74,12 +87,22 @@ pub enum Node {
     StringLiteral {
         value: String,
     },
+    InterpolatedString {
+        segments: Vec<Node>,
+    },
+    NullLiteral,
     ListLiteral {
         elements: Vec<Node>,
     },
     MapLiteral {
         entries: Vec<(Node, Node)>,
     },
+    SetLiteral {
+        elements: Vec<Node>,
+    },
+    TupleLiteral {
+        elements: Vec<Node>,
+    },
     PrefixExpression {
         operator: String,
         right: Box<Node>,
@@ -90,12 +113,19 @@ pub enum Node {
         right: Box<Node>,
     },
     AssignExpression {
+        identifier: Box<Node>,
+        operator: String,
+        value: Box<Node>,
+    },
+    DeclareExpression {
+        mutable: bool,
         identifier: Box<Node>,
         value: Box<Node>,
     },
     IndexExpression {
         collection: Box<Node>,
         index: Box<Node>,
+        optional: bool,
     },
     IfExpression {
         condition: Box<Node>,
@@ -111,16 +141,59 @@ pub enum Node {
     },
     FunctionLiteral {
         name: Option<Box<Node>>,
+        doc: Option<String>,
         parameters: Vec<Node>,
         body: Box<Node>,
     },
+    MatchExpression {
+        subject: Box<Node>,
+        arms: Vec<(Node, Option<Node>, Node)>,
+    },
+    TryExpression {
+        body: Box<Node>,
+        error: Option<Box<Node>>,
+        handler: Option<Box<Node>>,
+        finalizer: Option<Box<Node>>,
+    },
+    ThrowExpression {
+        value: Box<Node>,
+    },
+    PropagateExpression {
+        value: Box<Node>,
+    },
+    DeleteExpression {
+        target: Box<Node>,
+    },
+    RecordDeclaration {
+        name: Box<Node>,
+        fields: Vec<Node>,
+    },
+    ClassDeclaration {
+        name: Box<Node>,
+        parent: Option<Box<Node>>,
+        methods: Vec<Node>,
+    },
+    Super,
+    EnumDeclaration {
+        name: Box<Node>,
+        variants: Vec<(Node, Option<Vec<Node>>)>,
+    },
+    Wildcard,
+    SpreadElement {
+        value: Box<Node>,
+    },
     CallExpression {
         function: Box<Node>,
         arguments: Vec<Node>,
     },
+    NamedArgument {
+        name: Box<Node>,
+        value: Box<Node>,
+    },
     MemberExpression {
         instance: Box<Node>,
         member: Box<Node>,
+        optional: bool,
     },
 }
 
@@ -145,7 +218,21 @@ impl Node {
             Node::IntegerLiteral { value } => integer_to_emoji(value),
             Node::FloatLiteral { value } => float_to_emoji(value),
             Node::BooleanLiteral { value } => boolean_to_emoji(value),
-            Node::StringLiteral { value } => format!("🗨️{}💬", value),
+            Node::StringLiteral { value } => quote_string(value),
+            Node::InterpolatedString { segments } => format!(
+                "🗨️{}💬",
+                segments
+                    .iter()
+                    .map(|segment| match segment {
+                        Node::StringLiteral { value } => value
+                            .replace("🪄", "🪄🪄")
+                            .replace("🗨️", "🪄🗨️")
+                            .replace("💬", "🪄💬"),
+                        _ => format!("🪄🌜{}🌛", segment.string()),
+                    })
+                    .collect::<String>()
+            ),
+            Node::NullLiteral => null_to_emoji(),
             Node::ListLiteral { elements } => format!(
                 "👉{}👈",
                 elements
@@ -154,11 +241,33 @@ impl Node {
                     .collect::<Vec<String>>()
                     .join("🦶 ")
             ),
+            Node::SetLiteral { elements } => format!(
+                "🫸{}🫷",
+                elements
+                    .iter()
+                    .map(|exp| exp.string())
+                    .collect::<Vec<String>>()
+                    .join("🦶 ")
+            ),
+            Node::TupleLiteral { elements } if elements.len() == 1 => {
+                format!("🌜{}🦶🌛", elements[0].string())
+            }
+            Node::TupleLiteral { elements } => format!(
+                "🌜{}🌛",
+                elements
+                    .iter()
+                    .map(|exp| exp.string())
+                    .collect::<Vec<String>>()
+                    .join("🦶 ")
+            ),
             Node::MapLiteral { entries } => format!(
                 "🫸{}🫷",
                 entries
                     .iter()
-                    .map(|(key, value)| format!("{} ➡️ {}", key.string(), value.string()))
+                    .map(|(key, value)| match key {
+                        Node::SpreadElement { .. } => key.string(),
+                        _ => format!("{} ➡️ {}", key.string(), value.string()),
+                    })
                     .collect::<Vec<String>>()
                     .join("🦶 ")
             ),
@@ -170,13 +279,31 @@ impl Node {
                 operator,
                 right,
             } => format!("🌜{} {} {}🌛", left.string(), operator, right.string()),
-            Node::AssignExpression { identifier, value } => {
-                format!("{} ⬅️ {}", identifier.string(), value.string())
-            }
+            Node::AssignExpression {
+                identifier,
+                operator,
+                value,
+            } => format!("{} {} {}", identifier.string(), operator, value.string()),
+            Node::DeclareExpression {
+                mutable,
+                identifier,
+                value,
+            } => format!(
+                "{} {} ⬅️ {}",
+                if *mutable { "📝" } else { "🔒" },
+                identifier.string(),
+                value.string()
+            ),
             Node::IndexExpression {
                 collection: left,
                 index,
-            } => format!("{}👉{}👈", left.string(), index.string()),
+                optional,
+            } => format!(
+                "{}{}👉{}👈",
+                left.string(),
+                if *optional { "❔" } else { "" },
+                index.string()
+            ),
             Node::IfExpression {
                 condition,
                 consequence,
@@ -202,10 +329,15 @@ impl Node {
             ),
             Node::FunctionLiteral {
                 name,
+                doc,
                 parameters,
                 body,
             } => format!(
-                "📛 {}🌜{}🌛 {}",
+                "{}📛 {}🌜{}🌛 {}",
+                doc.as_ref().map_or(String::new(), |doc| doc
+                    .lines()
+                    .map(|line| format!("#️⃣📖 {line}\n"))
+                    .collect()),
                 name.as_ref()
                     .map_or(String::new(), |ident| ident.string() + " "),
                 parameters
@@ -215,6 +347,90 @@ impl Node {
                     .join("🦶 "),
                 body.string(),
             ),
+            Node::TryExpression {
+                body,
+                error,
+                handler,
+                finalizer,
+            } => format!(
+                "🤞 {}{}{}",
+                body.string(),
+                handler.as_ref().map_or(String::new(), |handler| format!(
+                    " 🥅 {}{}",
+                    error
+                        .as_ref()
+                        .map_or(String::new(), |error| error.string() + " "),
+                    handler.string()
+                )),
+                finalizer
+                    .as_ref()
+                    .map_or(String::new(), |finalizer| format!(" 🏁 {}", finalizer.string())),
+            ),
+            Node::ThrowExpression { value } => format!("🚨 {}", value.string()),
+            Node::DeleteExpression { target } => format!("🗑️ {}", target.string()),
+            Node::PropagateExpression { value } => format!("{}⁉️", value.string()),
+            Node::ClassDeclaration {
+                name,
+                parent,
+                methods,
+            } => format!(
+                "🏛️ {}{} 🫸 {} 🫷",
+                name.string(),
+                parent
+                    .as_ref()
+                    .map_or(String::new(), |parent| format!(" 🧬 {}", parent.string())),
+                methods
+                    .iter()
+                    .map(|method| format!("{} ↙️", method.string()))
+                    .collect::<String>()
+            ),
+            Node::Super => String::from("🦸"),
+            Node::EnumDeclaration { name, variants } => format!(
+                "🧩 {} 🫸 {} 🫷",
+                name.string(),
+                variants
+                    .iter()
+                    .map(|(variant, fields)| match fields {
+                        Some(fields) => format!(
+                            "{}🌜{}🌛",
+                            variant.string(),
+                            fields
+                                .iter()
+                                .map(|field| field.string())
+                                .collect::<Vec<String>>()
+                                .join("🦶 ")
+                        ),
+                        None => variant.string(),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("🦶 "),
+            ),
+            Node::RecordDeclaration { name, fields } => format!(
+                "🧱 {} 🫸 {} 🫷",
+                name.string(),
+                fields
+                    .iter()
+                    .map(|field| field.string())
+                    .collect::<Vec<String>>()
+                    .join("🦶 "),
+            ),
+            Node::MatchExpression { subject, arms } => format!(
+                "🎯 {} 🫸 {} 🫷",
+                subject.string(),
+                arms.iter()
+                    .map(|(pattern, guard, body)| format!(
+                        "{}{} ➡️ {}",
+                        pattern.string(),
+                        guard
+                            .as_ref()
+                            .map_or(String::new(), |guard| format!(" ❓ {}", guard.string())),
+                        body.string()
+                    ))
+                    .collect::<Vec<String>>()
+                    .join("🦶 ")
+            ),
+            Node::Wildcard => String::from("*️⃣"),
+            Node::SpreadElement { value } => format!("🧺{}", value.string()),
             Node::CallExpression {
                 function,
                 arguments,
@@ -227,9 +443,34 @@ impl Node {
                     .collect::<Vec<String>>()
                     .join("🦶 "),
             ),
-            Node::MemberExpression { instance, member } => {
-                format!("{}❇️{}", instance.string(), member.string(),)
+            Node::NamedArgument { name, value } => {
+                format!("{} ➡️ {}", name.string(), value.string())
             }
+            Node::MemberExpression {
+                instance,
+                member,
+                optional,
+            } => format!(
+                "{}{}❇️{}",
+                instance.string(),
+                if *optional { "❔" } else { "" },
+                member.string(),
+            ),
         }
     }
 }
+
+// strings containing 🪄 or quotes are printed as raw string literals
+fn quote_string(value: &str) -> String {
+    if !value.contains("🪄") && !QUOTES.iter().any(|quote| value.contains(quote)) {
+        return format!("🗨️{}💬", value);
+    }
+    let mut delimiter = String::from(RAW_STRING_DELIMITER);
+    while QUOTES
+        .iter()
+        .any(|quote| value.contains(&format!("{quote}{delimiter}")))
+    {
+        delimiter.push_str(RAW_STRING_DELIMITER);
+    }
+    format!("{delimiter}🗨️{value}💬{delimiter}")
+}
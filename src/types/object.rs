@@ -1,12 +1,20 @@
 use std::{
-    cell::RefCell, collections::HashMap, hash::Hash, rc::Rc
+    cell::RefCell, collections::{hash_map::DefaultHasher, HashMap, HashSet}, fmt, hash::{Hash, Hasher}, ops::Deref, rc::Rc
 };
 
+use indexmap::{IndexMap, IndexSet};
 use ordered_float::OrderedFloat;
 use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{types::Node, util::emoji_convert::object_to_emoji};
 
+// kinds of the errors raised by the evaluator, 💥 can create errors of any kind
+pub const GENERIC_ERROR: &str = "Error";
+pub const INDEX_ERROR: &str = "IndexError";
+pub const TYPE_ERROR: &str = "TypeError";
+pub const ZERO_DIVISION_ERROR: &str = "ZeroDivisionError";
+pub const OVERFLOW_ERROR: &str = "OverflowError";
+
 #[derive(Debug, Clone)]
 pub struct Object {
     value: ObjectValue,
@@ -51,16 +59,9 @@ impl Hash for Object {
             }
             ObjectValue::Map(entries) => {
                 6u32.hash(state);
-                for (key, value) in entries {
-                    key.hash(state);
-                    value.hash(state);
-                }
+                unordered_hash(entries.iter()).hash(state);
             }
-            ObjectValue::Function {
-                parameters: _,
-                body,
-                env: _,
-            } => {
+            ObjectValue::Function { body, .. } => {
                 7u32.hash(state);
                 body.string().hash(state);
             }
@@ -85,10 +86,71 @@ impl Hash for Object {
             ObjectValue::Continue => {
                 12u32.hash(state);
             }
+            ObjectValue::Error { kind, message } => {
+                13u32.hash(state);
+                kind.hash(state);
+                message.hash(state);
+            }
+            ObjectValue::Result(value) => {
+                14u32.hash(state);
+                value.hash(state);
+            }
+            ObjectValue::RecordType { name, .. } => {
+                15u32.hash(state);
+                name.hash(state);
+            }
+            ObjectValue::Record { type_name, fields } => {
+                16u32.hash(state);
+                type_name.hash(state);
+                fields.hash(state);
+            }
+            ObjectValue::Class(class) => {
+                17u32.hash(state);
+                class.name.hash(state);
+            }
+            ObjectValue::Instance { class, fields } => {
+                18u32.hash(state);
+                class.name.hash(state);
+                fields.hash(state);
+            }
+            ObjectValue::EnumType(enum_type) => {
+                19u32.hash(state);
+                enum_type.name.hash(state);
+            }
+            ObjectValue::VariantConstructor { enum_type, name } => {
+                20u32.hash(state);
+                enum_type.name.hash(state);
+                name.hash(state);
+            }
+            ObjectValue::Variant { enum_name, name, fields } => {
+                21u32.hash(state);
+                enum_name.hash(state);
+                name.hash(state);
+                fields.hash(state);
+            }
+            ObjectValue::Set(elements) => {
+                22u32.hash(state);
+                unordered_hash(elements.iter()).hash(state);
+            }
+            ObjectValue::Tuple(elements) => {
+                23u32.hash(state);
+                elements.hash(state);
+            }
         }
     }
 }
 
+// maps and sets are equal regardless of their insertion order, so their hash must not depend on it either
+fn unordered_hash<T: Hash>(items: impl Iterator<Item = T>) -> u64 {
+    items
+        .map(|item| {
+            let mut hasher = DefaultHasher::new();
+            item.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(0u64, u64::wrapping_add)
+}
+
 impl Object {
     pub fn inspect(&self) -> String {
         match &self.value {
@@ -113,12 +175,32 @@ impl Object {
                     .collect::<Vec<String>>()
                     .join(", ")
             ),
+            ObjectValue::Tuple(val) if val.len() == 1 => format!("({},)", val[0].inspect()),
+            ObjectValue::Tuple(val) => format!(
+                "({})",
+                val
+                    .iter()
+                    .map(|obj| obj.inspect())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ObjectValue::Set(val) if val.is_empty() => "set()".to_string(),
+            ObjectValue::Set(val) => format!(
+                "{{{}}}",
+                val
+                    .iter()
+                    .map(|obj| obj.inspect())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             ObjectValue::Function {
+                name,
                 parameters,
                 body,
-                env: _,
+                ..
             } => format!(
-                "fn({}) {}",
+                "fn{}({}) {}",
+                name.as_ref().map_or(String::new(), |name| format!(" {name}")),
                 parameters
                     .iter()
                     .map(|node| node.string())
@@ -134,6 +216,65 @@ impl Object {
             ObjectValue::ReturnValue(val) => val.inspect(),
             ObjectValue::Break(val) => val.clone().map_or("!".to_string(), |v| v.inspect()),
             ObjectValue::Continue => "!".to_string(),
+            ObjectValue::Error { kind, message } => format!("{kind}: {message}"),
+            ObjectValue::Result(Ok(val)) => format!("ok({})", val.inspect()),
+            ObjectValue::Result(Err(val)) => format!("err({})", val.inspect()),
+            ObjectValue::RecordType { name, fields, .. } => format!(
+                "record {}({})",
+                name,
+                fields
+                    .iter()
+                    .map(|node| node.string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ObjectValue::Record { type_name, fields } => format!(
+                "{}({})",
+                type_name,
+                fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value.inspect()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ObjectValue::Class(class) => format!("class {}", class.name),
+            ObjectValue::EnumType(enum_type) => format!("enum {}", enum_type.name),
+            ObjectValue::VariantConstructor { enum_type, name } => format!(
+                "{}❇️{}({})",
+                enum_type.name,
+                name,
+                enum_type
+                    .fields(name)
+                    .into_iter()
+                    .flatten()
+                    .map(|node| node.string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ObjectValue::Variant { enum_name, name, fields: None } => format!("{enum_name}❇️{name}"),
+            ObjectValue::Variant { enum_name, name, fields: Some(fields) } => format!(
+                "{}❇️{}({})",
+                enum_name,
+                name,
+                fields
+                    .iter()
+                    .map(|(name, value)| format!("{}: {}", name, value.inspect()))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            ObjectValue::Instance { class, fields } => fields
+                .visit(|fields| {
+                    format!(
+                        "{}({})",
+                        class.name,
+                        fields
+                            .iter()
+                            .map(|(name, value)| format!("{}: {}", name, value.inspect()))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                })
+                .unwrap_or_else(|| String::from(CYCLE)),
         }
     }
 
@@ -154,7 +295,7 @@ impl Object {
     }
 
     fn set_self_in_assoc_env(mut obj: Object) -> Object {
-        obj.associated_env.set("🈯".to_string(), obj.clone());
+        obj.associated_env.set_constant("🈯".to_string(), obj.clone());
         obj
     }
 
@@ -212,15 +353,39 @@ impl Object {
         )
     }
 
-    pub fn new_map(value: HashMap<Object, Object>) -> Object {
+    pub fn new_map(value: IndexMap<Object, Object>) -> Object {
         Self::set_self_in_assoc_env(
             Object {
                 value: ObjectValue::Map(value),
+                associated_env: Environment::new_builtins(&[BuiltinFunction::Len, BuiltinFunction::Contains]),
+            }
+        )
+    }
+
+    pub fn new_tuple(value: Vec<Object>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Tuple(value),
                 associated_env: Environment::new_builtins(&[BuiltinFunction::Len]),
             }
         )
     }
 
+    pub fn new_set(value: IndexSet<Object>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Set(value),
+                associated_env: Environment::new_builtins(&[
+                    BuiltinFunction::Len,
+                    BuiltinFunction::Contains,
+                    BuiltinFunction::Insert,
+                    BuiltinFunction::Remove,
+                    BuiltinFunction::ToList,
+                ]),
+            }
+        )
+    }
+
     pub fn new_butlin_function(value: BuiltinFunction) -> Object {
         Self::set_self_in_assoc_env(
             Object {
@@ -230,10 +395,18 @@ impl Object {
         )
     }
 
-    pub fn new_function(parameters: Vec<Node>, body: Box<Node>, env: Environment) -> Object {
+    pub fn new_function(
+        name: Option<String>,
+        doc: Option<String>,
+        parameters: Vec<Node>,
+        body: Box<Node>,
+        env: Environment,
+    ) -> Object {
         Self::set_self_in_assoc_env(
             Object {
                 value: ObjectValue::Function {
+                    name,
+                    doc,
                     parameters,
                     body,
                     env: Box::new(env),
@@ -243,6 +416,14 @@ impl Object {
         )
     }
 
+    /// The referenced object for references, the object itself otherwise.
+    pub fn dereference(self) -> Object {
+        match &self.value {
+            ObjectValue::Reference(value) => value.borrow().clone(),
+            _ => self,
+        }
+    }
+
     pub fn new_reference(value: Rc<RefCell<Object>>) -> Object {
         Self::set_self_in_assoc_env(
             Object {
@@ -252,6 +433,90 @@ impl Object {
         )
     }
 
+    pub fn new_error(kind: String, message: String) -> Object {
+        let mut obj = Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Error { kind: kind.clone(), message: message.clone() },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        );
+        obj.associated_env.set_constant("🏷️".to_string(), Object::new_string(kind));
+        obj.associated_env.set_constant("📨".to_string(), Object::new_string(message));
+        obj
+    }
+
+    pub fn new_result(value: Result<Object, Object>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Result(value.map(Box::new).map_err(Box::new)),
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_record_type(name: String, fields: Vec<Node>, env: Environment) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::RecordType { name, fields, env: Box::new(env) },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_record(type_name: String, fields: Vec<(String, Object)>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Record { type_name, fields },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_class(class: Rc<Class>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Class(class),
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_instance(class: Rc<Class>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Instance { class, fields: InstanceFields::default() },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_enum_type(enum_type: Rc<Enum>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::EnumType(enum_type),
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_variant_constructor(enum_type: Rc<Enum>, name: String) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::VariantConstructor { enum_type, name },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
+    pub fn new_variant(enum_name: String, name: String, fields: Option<Vec<(String, Object)>>) -> Object {
+        Self::set_self_in_assoc_env(
+            Object {
+                value: ObjectValue::Variant { enum_name, name, fields },
+                associated_env: Environment::new_builtins(&[]),
+            }
+        )
+    }
+
     pub fn new_return_value(value: Object) -> Object {
         Self::set_self_in_assoc_env(
             Object {
@@ -271,8 +536,14 @@ pub enum ObjectValue {
     String(String),
     Null,
     List(Vec<Object>),
-    Map(HashMap<Object, Object>),
+    // immutable, unlike lists
+    Tuple(Vec<Object>),
+    // insertion ordered, so output is reproducible
+    Map(IndexMap<Object, Object>),
+    Set(IndexSet<Object>),
     Function {
+        name: Option<String>,
+        doc: Option<String>,
         parameters: Vec<Node>,
         body: Box<Node>,
         env: Box<Environment>,
@@ -282,12 +553,145 @@ pub enum ObjectValue {
     ReturnValue(Box<Object>),
     Break(Option<Box<Object>>),
     Continue,
+    Error {
+        kind: String,
+        message: String,
+    },
+    Result(Result<Box<Object>, Box<Object>>),
+    RecordType {
+        name: String,
+        fields: Vec<Node>,
+        env: Box<Environment>,
+    },
+    Record {
+        type_name: String,
+        fields: Vec<(String, Object)>,
+    },
+    Class(Rc<Class>),
+    // instances share their fields, so methods can mutate them through 🈯
+    Instance {
+        class: Rc<Class>,
+        fields: InstanceFields,
+    },
+    EnumType(Rc<Enum>),
+    VariantConstructor {
+        enum_type: Rc<Enum>,
+        name: String,
+    },
+    // unit variants have no fields, unlike variants constructed with 🌜🌛
+    Variant {
+        enum_name: String,
+        name: String,
+        fields: Option<Vec<(String, Object)>>,
+    },
+}
+
+// printed in place of an instance that (indirectly) contains itself
+pub const CYCLE: &str = "<cycle>";
+
+type Fields = RefCell<Vec<(String, Object)>>;
+
+thread_local! {
+    // the fields of the instances being printed
+    static VISITING: RefCell<Vec<*const Fields>> = const { RefCell::new(vec![]) };
+}
+
+/// The fields of an instance, shared by all of its copies.
+/// Instances are compared and hashed by identity, as their fields can refer back to themselves.
+#[derive(Clone, Default)]
+pub struct InstanceFields(Rc<Fields>);
+
+impl InstanceFields {
+    /// Calls `f` with the fields, unless they are already being visited further up the stack.
+    pub fn visit<R>(&self, f: impl FnOnce(&[(String, Object)]) -> R) -> Option<R> {
+        let pointer = Rc::as_ptr(&self.0);
+        if VISITING.with_borrow(|visiting| visiting.contains(&pointer)) {
+            return None;
+        }
+        VISITING.with_borrow_mut(|visiting| visiting.push(pointer));
+        let result = f(&self.0.borrow());
+        VISITING.with_borrow_mut(|visiting| visiting.pop());
+        Some(result)
+    }
+}
+
+impl Deref for InstanceFields {
+    type Target = Fields;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl PartialEq for InstanceFields {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Hash for InstanceFields {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        Rc::as_ptr(&self.0).hash(state);
+    }
+}
+
+impl fmt::Debug for InstanceFields {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let names = self
+            .0
+            .borrow()
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<String>>();
+        f.debug_tuple("InstanceFields").field(&names).finish()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<(String, Option<Vec<Node>>)>,
+    pub env: Environment,
+}
+
+impl Enum {
+    pub fn variant(&self, name: &str) -> Option<&(String, Option<Vec<Node>>)> {
+        self.variants.iter().find(|(variant, _)| variant == name)
+    }
+
+    pub fn fields(&self, name: &str) -> Option<&Vec<Node>> {
+        self.variant(name)?.1.as_ref()
+    }
+}
+
+#[derive(PartialEq, Debug)]
+pub struct Class {
+    pub name: String,
+    pub parent: Option<Rc<Class>>,
+    pub methods: HashMap<String, Object>,
+}
+
+impl Class {
+    pub fn method(&self, name: &str) -> Option<&Object> {
+        self.methods
+            .get(name)
+            .or_else(|| self.parent.as_ref()?.method(name))
+    }
+
+    pub fn is_subclass_of(&self, other: &Class) -> bool {
+        std::ptr::eq(self, other)
+            || self
+                .parent
+                .as_ref()
+                .is_some_and(|parent| parent.is_subclass_of(other))
+    }
 }
 
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Environment {
     map: HashMap<String, Rc<RefCell<Object>>>,
+    constants: HashSet<String>,
     outer: Option<Box<Environment>>,
 }
 
@@ -296,13 +700,15 @@ impl Environment {
         let mut map = HashMap::new();
 
         BuiltinFunction::register_exports(&mut map);
+        let constants = map.keys().cloned().collect();
 
-        Environment { map, outer: None }
+        Environment { map, constants, outer: None }
     }
 
     pub fn new_enclosed(outer: Box<Environment>) -> Self {
         Environment {
             map: HashMap::new(),
+            constants: HashSet::new(),
             outer: Some(outer),
         }
     }
@@ -311,12 +717,57 @@ impl Environment {
         let mut map = HashMap::new();
 
         BuiltinFunction::register(builtin_functions, &mut map);
+        let constants = map.keys().cloned().collect();
 
-        Environment { map, outer: None }
+        Environment { map, constants, outer: None }
     }
 
+    /// Stores the value in the existing binding of this scope, so its captures see it, or in a new binding.
     pub fn set(&mut self, identifier: String, value: Object) {
+        match self.map.get(&identifier) {
+            Some(storage) => *storage.borrow_mut() = value,
+            None => {
+                self.map.insert(identifier, Rc::new(RefCell::new(value)));
+            }
+        }
+    }
+
+    pub fn set_constant(&mut self, identifier: String, value: Object) {
+        self.constants.insert(identifier.clone());
+        self.set(identifier, value);
+    }
+
+    /// Assigns to an existing or new binding, refusing to overwrite a constant.
+    pub fn assign(&mut self, identifier: String, value: Object) -> Result<(), String> {
+        if self.is_constant(&identifier) {
+            return Err(format!("Cannot assign to constant {identifier}"));
+        }
+        self.set(identifier, value);
+        Ok(())
+    }
+
+    /// Declares a binding in this scope, shadowing any outer binding of the same name.
+    pub fn declare(&mut self, identifier: String, value: Object, mutable: bool) -> Result<(), String> {
+        if self.constants.contains(&identifier) {
+            return Err(format!("Cannot redeclare constant {identifier}"));
+        }
+        if !mutable {
+            self.constants.insert(identifier.clone());
+        }
+        // a new binding, detached from the storage of the previous one
         self.map.insert(identifier, Rc::new(RefCell::new(value)));
+        Ok(())
+    }
+
+    // whether the binding `get` resolves to is a constant
+    pub fn is_constant(&self, identifier: &String) -> bool {
+        if self.map.contains_key(identifier) {
+            self.constants.contains(identifier)
+        } else {
+            self.outer
+                .as_ref()
+                .is_some_and(|outer| outer.is_constant(identifier))
+        }
     }
 
     pub fn get(&self, identifier: &String) -> Option<&Rc<RefCell<Object>>> {
@@ -337,16 +788,32 @@ pub enum BuiltinFunction {
     ToString,
     Print,
     Println,
+    Error,
+    Ok,
+    Err,
+    IsInstance,
+    TypeOf,
+    SetOf,
 
     Pow,
     Len,
+    Contains,
+    Insert,
+    Remove,
+    ToList,
 }
 
 impl BuiltinFunction {
-    const EXPORTS: [BuiltinFunction; 3] = [
+    const EXPORTS: [BuiltinFunction; 9] = [
         BuiltinFunction::ToString,
         BuiltinFunction::Print,
         BuiltinFunction::Println,
+        BuiltinFunction::Error,
+        BuiltinFunction::Ok,
+        BuiltinFunction::Err,
+        BuiltinFunction::IsInstance,
+        BuiltinFunction::TypeOf,
+        BuiltinFunction::SetOf,
     ];
 
     pub fn name(&self) -> String {
@@ -354,9 +821,40 @@ impl BuiltinFunction {
             BuiltinFunction::ToString => String::from("👁️‍🗨️"),
             BuiltinFunction::Print => String::from("🖨️"),
             BuiltinFunction::Println => String::from("🖨️↩️"),
+            BuiltinFunction::Error => String::from("💥"),
+            BuiltinFunction::Ok => String::from("🆗"),
+            BuiltinFunction::Err => String::from("🆖"),
+            BuiltinFunction::IsInstance => String::from("🧪"),
+            BuiltinFunction::TypeOf => String::from("🔖"),
+            BuiltinFunction::SetOf => String::from("🎒"),
 
             BuiltinFunction::Pow => String::from("💕"),
             BuiltinFunction::Len => String::from("📏"),
+            BuiltinFunction::Contains => String::from("🔍"),
+            BuiltinFunction::Insert => String::from("📌"),
+            BuiltinFunction::Remove => String::from("✂️"),
+            BuiltinFunction::ToList => String::from("📋"),
+        }
+    }
+
+    pub fn doc(&self) -> &'static str {
+        match self {
+            BuiltinFunction::ToString => "Converts the value to its emoji string representation.",
+            BuiltinFunction::Print => "Prints the value without a trailing line break.",
+            BuiltinFunction::Println => "Prints the value followed by a line break.",
+            BuiltinFunction::Error => "Creates an error to 🚨 from a message, optionally preceded by its kind.",
+            BuiltinFunction::Ok => "Wraps the value in a successful result.",
+            BuiltinFunction::Err => "Wraps the value in a failed result.",
+            BuiltinFunction::IsInstance => "Checks whether the value is an instance of the class (or its subclasses), record, enum or variant type.",
+            BuiltinFunction::TypeOf => "Returns the name of the type of the value.",
+            BuiltinFunction::SetOf => "Creates a set from the elements of a list, set, map (its keys) or string, or an empty set.",
+
+            BuiltinFunction::Pow => "Raises the number to the given power.",
+            BuiltinFunction::Len => "Returns the number of elements of a string, list, tuple, map or set.",
+            BuiltinFunction::Contains => "Checks whether the set contains the value, or the map the key.",
+            BuiltinFunction::Insert => "Returns a copy of the set with the value added.",
+            BuiltinFunction::Remove => "Returns a copy of the set without the value.",
+            BuiltinFunction::ToList => "Returns the elements of the set as a list.",
         }
     }
 
@@ -365,9 +863,19 @@ impl BuiltinFunction {
             BuiltinFunction::ToString => Rc::new(BuiltinFunction::to_string) as FunctionWrapper,
             BuiltinFunction::Print => Rc::new(BuiltinFunction::print) as FunctionWrapper,
             BuiltinFunction::Println => Rc::new(BuiltinFunction::println) as FunctionWrapper,
+            BuiltinFunction::Error => Rc::new(BuiltinFunction::error) as FunctionWrapper,
+            BuiltinFunction::Ok => Rc::new(BuiltinFunction::ok) as FunctionWrapper,
+            BuiltinFunction::Err => Rc::new(BuiltinFunction::err) as FunctionWrapper,
+            BuiltinFunction::IsInstance => Rc::new(BuiltinFunction::is_instance) as FunctionWrapper,
+            BuiltinFunction::TypeOf => Rc::new(BuiltinFunction::type_of) as FunctionWrapper,
+            BuiltinFunction::SetOf => Rc::new(BuiltinFunction::set_of) as FunctionWrapper,
 
             BuiltinFunction::Pow => Rc::new(BuiltinFunction::pow) as FunctionWrapper,
             BuiltinFunction::Len => Rc::new(BuiltinFunction::len) as FunctionWrapper,
+            BuiltinFunction::Contains => Rc::new(BuiltinFunction::contains) as FunctionWrapper,
+            BuiltinFunction::Insert => Rc::new(BuiltinFunction::insert) as FunctionWrapper,
+            BuiltinFunction::Remove => Rc::new(BuiltinFunction::remove) as FunctionWrapper,
+            BuiltinFunction::ToList => Rc::new(BuiltinFunction::to_list) as FunctionWrapper,
         }
     }
 
@@ -411,6 +919,103 @@ impl BuiltinFunction {
         Ok(Object::new_null())
     }
 
+    fn error(args: &[Object]) -> Result<Object, String> {
+        match args.iter().map(|arg| arg.value()).collect::<Vec<&ObjectValue>>().as_slice() {
+            [ObjectValue::String(message)] => {
+                Ok(Object::new_error(GENERIC_ERROR.to_string(), message.clone()))
+            }
+            [ObjectValue::String(kind), ObjectValue::String(message)] => {
+                Ok(Object::new_error(kind.clone(), message.clone()))
+            }
+            _ => Err(format!("Expected ([kind, ]message) strings, but got {:?}", args)),
+        }
+    }
+
+    fn ok(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 1 {
+            return Err(format!("Expected 1 argument(s), but got {}", args.len()));
+        }
+        Ok(Object::new_result(Ok(args[0].clone())))
+    }
+
+    fn err(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 1 {
+            return Err(format!("Expected 1 argument(s), but got {}", args.len()));
+        }
+        Ok(Object::new_result(Err(args[0].clone())))
+    }
+
+    fn is_instance(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 2 {
+            return Err(format!("Expected 2 argument(s), but got {}", args.len()));
+        }
+        let is_instance = match (args[0].value(), args[1].value()) {
+            (ObjectValue::Instance { class, .. }, ObjectValue::Class(other)) => class.is_subclass_of(other),
+            (ObjectValue::Record { type_name, .. }, ObjectValue::RecordType { name, .. }) => type_name == name,
+            (ObjectValue::Variant { enum_name, .. }, ObjectValue::EnumType(enum_type)) => *enum_name == enum_type.name,
+            (
+                ObjectValue::Variant { enum_name, name, .. },
+                ObjectValue::VariantConstructor { enum_type, name: variant },
+            ) => *enum_name == enum_type.name && name == variant,
+            (
+                _,
+                ObjectValue::Class(_)
+                | ObjectValue::RecordType { .. }
+                | ObjectValue::EnumType(_)
+                | ObjectValue::VariantConstructor { .. },
+            ) => false,
+            (_, other) => return Err(format!("Expected a class, record, enum or variant type, but got {:?}", other)),
+        };
+        Ok(Object::new_boolean(is_instance))
+    }
+
+    fn type_of(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 1 {
+            return Err(format!("Expected 1 argument(s), but got {}", args.len()));
+        }
+        let name = match args[0].value() {
+            ObjectValue::Integer(_) => "integer",
+            ObjectValue::Float(_) => "float",
+            ObjectValue::Boolean(_) => "boolean",
+            ObjectValue::String(_) => "string",
+            ObjectValue::Null => "null",
+            ObjectValue::List(_) => "list",
+            ObjectValue::Map(_) => "map",
+            ObjectValue::Set(_) => "set",
+            ObjectValue::Tuple(_) => "tuple",
+            ObjectValue::Function { .. }
+            | ObjectValue::BuiltinFunction(_)
+            | ObjectValue::VariantConstructor { .. } => "function",
+            ObjectValue::Error { .. } => "error",
+            ObjectValue::Result(_) => "result",
+            ObjectValue::RecordType { .. } => "record type",
+            ObjectValue::Class(_) => "class",
+            ObjectValue::EnumType(_) => "enum",
+            ObjectValue::Variant { enum_name, .. } => enum_name,
+            ObjectValue::Record { type_name, .. } => type_name,
+            ObjectValue::Instance { class, .. } => &class.name,
+            ObjectValue::Reference(_) | ObjectValue::ReturnValue(_) | ObjectValue::Break(_) | ObjectValue::Continue => {
+                return Err(format!("Unexpected internal value {:?}", args[0]));
+            }
+        };
+        Ok(Object::new_string(name.to_string()))
+    }
+
+    fn set_of(args: &[Object]) -> Result<Object, String> {
+        let elements = match args.iter().map(|arg| arg.value()).collect::<Vec<&ObjectValue>>().as_slice() {
+            [] => IndexSet::new(),
+            [ObjectValue::List(value)] => value.iter().cloned().collect(),
+            [ObjectValue::Set(value)] => value.clone(),
+            [ObjectValue::Map(value)] => value.keys().cloned().collect(),
+            [ObjectValue::String(value)] => value
+                .graphemes(true)
+                .map(|grapheme| Object::new_string(grapheme.to_string()))
+                .collect(),
+            _ => return Err(format!("Expected no argument or a list/set/map/string, but got {:?}", args)),
+        };
+        Ok(Object::new_set(elements))
+    }
+
     // builtin method implementations
 
     fn pow(args: &[Object]) -> Result<Object, String> {
@@ -462,13 +1067,59 @@ impl BuiltinFunction {
 
         let length = match args.first().unwrap().value() {
             ObjectValue::String(value) => value.graphemes(true).count(),
-            ObjectValue::List(value) => value.len(),
+            ObjectValue::List(value) | ObjectValue::Tuple(value) => value.len(),
             ObjectValue::Map(value) => value.len(),
-            object => return Err(format!("Expected string/list/map as instance, but got {:?}", object))
+            ObjectValue::Set(value) => value.len(),
+            object => return Err(format!("Expected string/list/tuple/map/set as instance, but got {:?}", object))
         };
 
         i64::try_from(length)
             .map(Object::new_integer)
             .map_err(|_| String::from("Calculation overflow: len()"))
     }
+
+    fn set_and_value(args: &[Object]) -> Result<(&IndexSet<Object>, &Object), String> {
+        if args.len() != 2 {
+            return Err(format!("Expected 2 argument(s), but got {}", args.len()));
+        }
+        match args[0].value() {
+            ObjectValue::Set(elements) => Ok((elements, &args[1])),
+            object => Err(format!("Expected set as instance, but got {:?}", object)),
+        }
+    }
+
+    fn contains(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 2 {
+            return Err(format!("Expected 2 argument(s), but got {}", args.len()));
+        }
+        match args[0].value() {
+            ObjectValue::Set(elements) => Ok(Object::new_boolean(elements.contains(&args[1]))),
+            ObjectValue::Map(entries) => Ok(Object::new_boolean(entries.contains_key(&args[1]))),
+            object => Err(format!("Expected set/map as instance, but got {:?}", object)),
+        }
+    }
+
+    fn insert(args: &[Object]) -> Result<Object, String> {
+        let (elements, value) = BuiltinFunction::set_and_value(args)?;
+        let mut elements = elements.clone();
+        elements.insert(value.clone());
+        Ok(Object::new_set(elements))
+    }
+
+    fn remove(args: &[Object]) -> Result<Object, String> {
+        let (elements, value) = BuiltinFunction::set_and_value(args)?;
+        let mut elements = elements.clone();
+        elements.shift_remove(value);
+        Ok(Object::new_set(elements))
+    }
+
+    fn to_list(args: &[Object]) -> Result<Object, String> {
+        if args.len() != 1 {
+            return Err(format!("Expected 1 argument(s), but got {}", args.len()));
+        }
+        match args[0].value() {
+            ObjectValue::Set(elements) => Ok(Object::new_list(elements.iter().cloned().collect())),
+            object => Err(format!("Expected set as instance, but got {:?}", object)),
+        }
+    }
 }
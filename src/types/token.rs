@@ -4,8 +4,14 @@ pub enum TokenType {
     Start,
 
     Assign,
+    PlusAssign,
+    MinusAssign,
+    MultiplyAssign,
+    DivideAssign,
+    ModuloAssign,
     Describe,
     Member,
+    OptionalMember,
 
     Plus,
     Minus,
@@ -23,12 +29,17 @@ pub enum TokenType {
     And,
     Or,
     Not,
+    NullCoalescing,
+    Pipe,
+    In,
 
     Comma,
     Semicolon,
     LParenthesis,
     RParenthesis,
     LBracket,
+    OptionalLBracket,
+    Propagate,
     RBracket,
     LBrace,
     RBrace,
@@ -37,6 +48,7 @@ pub enum TokenType {
 
     True,
     False,
+    Null,
 
     If,
     Else,
@@ -44,22 +56,42 @@ pub enum TokenType {
     Continue,
     Break,
     Function,
+    Lambda,
     Return,
+    Match,
+    Wildcard,
+    Spread,
+    DocComment,
+    Let,
+    Const,
+    Try,
+    Catch,
+    Finally,
+    Throw,
+    Record,
+    Class,
+    Inherits,
+    Super,
+    Enum,
+    Delete,
 
     Integer,
     Float,
     String,
 }
 
-pub const RESERVED_SYMBOLS: [&str; 35] = [
+pub const RESERVED_SYMBOLS: [&str; 56] = [
     "⬅️", "➡️", "➕", "➖", "✖️", "➗", "〰️", "🟰", "▶️", "◀️", "🔁", "🔀", "⏸️", "↙️", "🦶", "🌜", "🌛",
     "👉", "👈", "🫸", "🫷", "🪄", "❇️", "✔️", "❌", "❓", "❗", "⁉️", "⭕", "🔜", "🔚", "📛", "🔙", "#️⃣", "🈳",
+    "❔", "🎯", "*️⃣", "🧺", "⚡", "⏩", "📜", "📄", "📝", "🔒", "🤞", "🥅", "🏁", "🚨", "🧱", "🏛️", "🧬", "🦸", "🧩", "📥", "🗑️",
 ];
 pub const DIGITALS: [&str; 10] = ["0️⃣", "1️⃣", "2️⃣", "3️⃣", "4️⃣", "5️⃣", "6️⃣", "7️⃣", "8️⃣", "9️⃣"];
 pub const DOTS: [&str; 9] = ["⚪", "⚫", "🟤", "🟣", "🔵", "🟢", "🟡", "🟠", "🔴"];
 pub const SPACES: [&str; 2] = [" ", "\t"];
 pub const NEWLINES: [&str; 3] = ["\r", "\n", "\r\n"];
 pub const QUOTES: [&str; 2] = ["🗨️", "💬"];
+pub const RAW_STRING_DELIMITER: &str = "📜";
+pub const MULTILINE_STRING_PREFIX: &str = "📄";
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct Token {
@@ -86,4 +118,8 @@ impl Token {
     pub fn this() -> Token {
         Token::from(TokenType::Identifier, String::from("🈯"))
     }
+
+    pub fn constructor() -> Token {
+        Token::from(TokenType::Identifier, String::from("🆕"))
+    }
 }
@@ -37,6 +37,10 @@ impl<T: PartialEq> StatefulVector<T> {
         self.vector.last()
     }
 
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
     pub fn current(&self) -> Option<&T> {
         if self.is_pos_valid(self.position) {
             Some(&self.vector[self.position])
@@ -63,6 +67,10 @@ impl<T: PartialEq> StatefulVector<T> {
         }
     }
 
+    pub fn peek(&self, offset: usize) -> Option<&T> {
+        self.vector.get(self.position + offset)
+    }
+
     pub fn is_next_eq(&self, expected_element: &T) -> bool {
         self.is_next_match(|ele| *ele == *expected_element)
     }
@@ -125,7 +133,7 @@ impl<T: PartialEq> StatefulVector<T> {
 }
 
 pub mod emoji_convert {
-    use crate::types::{Object, ObjectValue};
+    use crate::types::{object::CYCLE, Object, ObjectValue};
 
     const DOT: char = '.';
     const NULL_EMOJI: &str = "🈳";
@@ -166,13 +174,17 @@ pub mod emoji_convert {
         String::from(if *boolean { "✔️" } else { "❌" })
     }
 
+    pub fn null_to_emoji() -> String {
+        String::from(NULL_EMOJI)
+    }
+
     pub fn object_to_emoji(object: &Object) -> Result<String, String> {
         let string = match object.value() {
             ObjectValue::Integer(value) => integer_to_emoji(value),
             ObjectValue::Float(value) => float_to_emoji(value),
             ObjectValue::Boolean(value) => boolean_to_emoji(value),
             ObjectValue::String(value) => value.clone(),
-            ObjectValue::Null => String::from(NULL_EMOJI),
+            ObjectValue::Null => null_to_emoji(),
             ObjectValue::List(value) => {
                 let mut elements = vec![];
                 for element in value {
@@ -187,7 +199,53 @@ pub mod emoji_convert {
                 }
                 format!("🫸{}🫷", entries.join("🦶 "))
             },
+            ObjectValue::Tuple(value) => {
+                let mut elements = vec![];
+                for element in value {
+                    elements.push(object_to_emoji(element)?);
+                }
+                if elements.len() == 1 {
+                    format!("🌜{}🦶🌛", elements[0])
+                } else {
+                    format!("🌜{}🌛", elements.join("🦶 "))
+                }
+            },
+            ObjectValue::Set(value) if value.is_empty() => String::from("🎒🌜🌛"),
+            ObjectValue::Set(value) => {
+                let mut elements = vec![];
+                for element in value {
+                    elements.push(object_to_emoji(element)?);
+                }
+                format!("🫸{}🫷", elements.join("🦶 "))
+            },
             ObjectValue::ReturnValue(value) => object_to_emoji(value)?,
+            ObjectValue::Error { kind, message } => format!("{kind}: {message}"),
+            ObjectValue::Result(Ok(value)) => format!("🆗🌜{}🌛", object_to_emoji(value)?),
+            ObjectValue::Result(Err(value)) => format!("🆖🌜{}🌛", object_to_emoji(value)?),
+            ObjectValue::Record { type_name, fields } => {
+                let mut entries = vec![];
+                for (name, value) in fields {
+                    entries.push(format!("{} ➡️ {}", name, object_to_emoji(value)?));
+                }
+                format!("{}🌜{}🌛", type_name, entries.join("🦶 "))
+            },
+            ObjectValue::Variant { enum_name, name, fields: None } => format!("{enum_name}❇️{name}"),
+            ObjectValue::Variant { enum_name, name, fields: Some(fields) } => {
+                let mut entries = vec![];
+                for (name, value) in fields {
+                    entries.push(format!("{} ➡️ {}", name, object_to_emoji(value)?));
+                }
+                format!("{}❇️{}🌜{}🌛", enum_name, name, entries.join("🦶 "))
+            },
+            ObjectValue::Instance { class, fields } => fields
+                .visit(|fields| {
+                    let mut entries = vec![];
+                    for (name, value) in fields {
+                        entries.push(format!("{} ➡️ {}", name, object_to_emoji(value)?));
+                    }
+                    Ok::<String, String>(format!("{}🌜{}🌛", class.name, entries.join("🦶 ")))
+                })
+                .unwrap_or_else(|| Ok(String::from(CYCLE)))?,
             _ => return Err(format!("Incompatible argument type with string: {:?}", object)),
         };
         Ok(string)